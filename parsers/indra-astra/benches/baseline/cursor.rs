@@ -0,0 +1,117 @@
+//! The cursor's reading and backtracking from before it borrowed its source; with logging and the parser context left out.
+
+#[derive(Clone)]
+pub struct Indents {
+    pub curr: usize,
+    pub stack: Vec<usize>,
+    pub is_reading: bool,
+}
+
+impl Indents {
+    pub fn prev(&self) -> usize {
+        return self.stack.last().unwrap_or(&0).clone();
+    }
+}
+
+pub struct Cursor {
+    src: Vec<char>,
+    indents: Indents,
+    pos: usize,
+    state: Vec<State>,
+}
+
+pub struct State {
+    pub pos: usize,
+    pub indents: Indents,
+}
+
+impl Cursor {
+    #[allow(non_snake_case)]
+    pub fn New(source: &str) -> Cursor {
+        let src: Vec<char> = (source.to_string() + "\0").chars().collect();
+        Cursor {
+            pos: 0,
+            src,
+            indents: Indents {
+                stack: Vec::new(),
+                is_reading: true,
+                curr: 0,
+            },
+            state: Vec::new(),
+        }
+    }
+
+    pub fn state(&self) -> State {
+        State {
+            pos: self.pos,
+            indents: self.indents.clone(),
+        }
+    }
+
+    pub fn save(&mut self) -> usize {
+        let state = self.state();
+        self.state.push(state);
+
+        self.pos
+    }
+
+    pub fn restore(&mut self) -> usize {
+        let state = self.state.pop().unwrap();
+        self.pos = state.pos;
+        self.indents = state.indents;
+
+        self.pos
+    }
+
+    pub fn skip(&mut self) {
+        self._update_indents();
+        self.pos += 1;
+    }
+
+    fn _update_indents(&mut self) {
+        match self.curr() {
+            '\n' => {
+                if self.indents.prev() != self.indents.curr {
+                    self.indents.stack.push(self.indents.curr.to_owned());
+                }
+
+                self.indents.curr = 0;
+                self.indents.is_reading = true;
+            }
+            '\t' | ' ' => {
+                if self.indents.is_reading {
+                    self.indents.curr += 1;
+                }
+            }
+            _ => {
+                if self.indents.is_reading {
+                    self.indents.is_reading = false;
+                }
+            }
+        }
+    }
+
+    pub fn curr(&self) -> char {
+        return self.at(self.pos);
+    }
+
+    pub fn curr_pos(&self) -> usize {
+        return self.pos;
+    }
+
+    pub fn at(&self, pos: usize) -> char {
+        return self.src[pos];
+    }
+
+    pub fn is_eof(&self) -> bool {
+        return self.eof_at(self.pos);
+    }
+
+    pub fn eof_at(&self, pos: usize) -> bool {
+        return pos >= self.src.len() - 1;
+    }
+
+    pub fn slice(&self, start: usize, end: usize) -> String {
+        return self.src[start..end].iter().collect();
+    }
+}
@@ -0,0 +1,119 @@
+#![feature(test)]
+extern crate test;
+
+#[path = "baseline/cursor.rs"]
+mod baseline;
+
+use astra::parser::{
+    cursor::{Branch, Cursor},
+    tokens::source,
+    Parser,
+};
+use test::{black_box, Bencher};
+
+/// A data file of roughly the given size in bytes.
+fn data_file(size: usize) -> String {
+    let mut source = String::new();
+    let mut index = 0;
+    while source.len() < size {
+        source.push_str(&format!(
+            "entry_{index}: some words in a sentence\n\tnested_{index}: more wörds here\n"
+        ));
+        index += 1;
+    }
+
+    source
+}
+
+#[bench]
+fn new_borrowed_4mb(b: &mut Bencher) {
+    let source = data_file(4_000_000);
+    b.iter(|| black_box(Cursor::New(&source)));
+}
+
+#[bench]
+fn new_baseline_4mb(b: &mut Bencher) {
+    let source = data_file(4_000_000);
+    b.iter(|| black_box(baseline::Cursor::New(&source)));
+}
+
+#[bench]
+fn walk_borrowed_4mb(b: &mut Bencher) {
+    let source = data_file(4_000_000);
+    b.iter(|| {
+        let mut cursor = Cursor::New(&source);
+        while !cursor.is_eof() {
+            black_box(cursor.curr());
+            cursor.skip();
+        }
+    });
+}
+
+#[bench]
+fn walk_baseline_4mb(b: &mut Bencher) {
+    let source = data_file(4_000_000);
+    b.iter(|| {
+        let mut cursor = baseline::Cursor::New(&source);
+        while !cursor.is_eof() {
+            black_box(cursor.curr());
+            cursor.skip();
+        }
+    });
+}
+
+/// Reads each line twice; reverting the first read like a failed optional parser, then slicing the second.
+#[bench]
+fn backtrack_borrowed_200kb(b: &mut Bencher) {
+    let source = data_file(200_000);
+    b.iter(|| {
+        let mut cursor = Cursor::New(&source);
+        while !cursor.is_eof() {
+            cursor.split(|cursor| {
+                while !cursor.is_eof() && !cursor.curr_is('\n') {
+                    cursor.skip();
+                }
+                Branch::Revert
+            });
+
+            let start = cursor.curr_pos();
+            while !cursor.is_eof() && !cursor.curr_is('\n') {
+                cursor.skip();
+            }
+            black_box(cursor.slice(start, cursor.curr_pos()));
+            if !cursor.is_eof() {
+                cursor.skip();
+            }
+        }
+    });
+}
+
+/// Reads each line twice; reverting the first read like a failed optional parser, then slicing the second.
+#[bench]
+fn backtrack_baseline_200kb(b: &mut Bencher) {
+    let source = data_file(200_000);
+    b.iter(|| {
+        let mut cursor = baseline::Cursor::New(&source);
+        while !cursor.is_eof() {
+            cursor.save();
+            while !cursor.is_eof() && cursor.curr() != '\n' {
+                cursor.skip();
+            }
+            cursor.restore();
+
+            let start = cursor.curr_pos();
+            while !cursor.is_eof() && cursor.curr() != '\n' {
+                cursor.skip();
+            }
+            black_box(cursor.slice(start, cursor.curr_pos()));
+            if !cursor.is_eof() {
+                cursor.skip();
+            }
+        }
+    });
+}
+
+#[bench]
+fn parse_data_file_20kb(b: &mut Bencher) {
+    let source = data_file(20_000);
+    b.iter(|| black_box(source::Parser::Parse(&source)));
+}
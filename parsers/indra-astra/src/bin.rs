@@ -1,7 +1,33 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+
 use astra::{
-    parser::Parser,
+    parser::{
+        context::Context,
+        cst::Cst,
+        cursor::Cursor,
+        format, fs,
+        grammar::Grammar,
+        indents::{IndentStyle, Indentation},
+        results::{
+            error::{Error, CHOICE_TAG},
+            node::Node,
+            parsed::Parsed,
+        },
+        source_map::SourceMap,
+        tokens::source,
+        Parser,
+    },
     tests::parser::tokens::tests,
-    utils::{log, sexp::SFormat},
+    utils::{
+        log,
+        sexp::{SExpressable, SFormat},
+    },
 };
 use clap::{Parser as Arguments, Subcommand, ValueEnum};
 
@@ -29,6 +55,10 @@ struct TestArgs {
     /// Whether to panic on the first test failure
     #[arg(short, long, default_value_t = false)]
     panic_on_fail: bool,
+
+    /// The number of random edits to re-parse each test input with; checking each against a fresh parse
+    #[arg(short, long, default_value_t = tests::DEFAULT_RANDOM_EDITS)]
+    edits: usize,
 }
 
 #[derive(Arguments, Debug)]
@@ -58,13 +88,78 @@ enum Commands {
         #[arg(short, long)]
         file: Option<String>,
 
-        /// The file to write output to
+        /// The file to write output to; or the folder to write each file's output to, if parsing a folder
         #[arg(short, long)]
         out: Option<String>,
 
         /// The data to include in the output
         #[arg(short, long)]
         data: Option<Data>,
+
+        /// Whether to memoize parser results, and report the memo's hits/misses
+        #[arg(short, long, default_value_t = false)]
+        memo: bool,
+
+        /// Whether to keep parsing after errors, and report every error found
+        #[arg(short, long, default_value_t = false)]
+        recover: bool,
+
+        /// Whether to include the byte, utf-16 and line/column positions of each token in json output
+        #[arg(short, long, default_value_t = false)]
+        positions: bool,
+
+        #[command(flatten)]
+        parsing: ParsingArgs,
+
+        /// The number of threads to parse the files in a folder with (defaults to the number of cpus)
+        #[arg(short, long)]
+        jobs: Option<usize>,
+
+        /// Whether to output a lossless concrete syntax tree; where every char of the input belongs to a leaf
+        #[arg(short, long, default_value_t = false)]
+        lossless: bool,
+
+        /// A selector for the tokens to output; like `named_entry > .key` or `*#expression`
+        #[arg(short, long)]
+        select: Option<String>,
+    },
+    Trace {
+        /// Code input to trace the parsing of
+        #[arg(num_args(0..))]
+        input: Option<Vec<String>>,
+
+        /// The output format to use
+        #[arg(short, long, value_enum)]
+        to: Option<TraceOutputs>,
+
+        /// The file path to read input from
+        #[arg(short, long)]
+        file: Option<String>,
+
+        #[command(flatten)]
+        parsing: ParsingArgs,
+    },
+    Fmt {
+        /// The paths of the files to format
+        #[arg(num_args(1..), required = true)]
+        files: Vec<String>,
+
+        /// Whether to only check that the files are formatted; exiting with an error if any aren't
+        #[arg(short, long, default_value_t = false)]
+        check: bool,
+
+        /// Whether to write the formatted files in place; instead of printing them
+        #[arg(short, long, default_value_t = false)]
+        in_place: bool,
+    },
+    Grammar {
+        /// The output format to use
+        #[arg(short, long, value_enum)]
+        to: Option<GrammarOutputs>,
+
+        /// The file to write output to
+        #[arg(short, long)]
+        out: Option<String>,
     },
 }
 
@@ -82,6 +177,392 @@ enum Outputs {
     Sexp,
 }
 
+/// The options shared by each command that parses its input.
+#[derive(clap::Args, Debug)]
+struct ParsingArgs {
+    /// The name of the grammar rule to parse the input with (defaults to a source file)
+    #[arg(long)]
+    rule: Option<String>,
+
+    /// The chars lines may be indented with (defaults to either, mixed freely)
+    #[arg(long, value_enum)]
+    indent: Option<Indent>,
+
+    /// The number of columns a tab counts as when measuring indentation
+    #[arg(long, default_value_t = 1)]
+    tab_width: usize,
+
+    /// The max number of parser attempts to make before failing
+    #[arg(long)]
+    fuel: Option<usize>,
+
+    /// The max number of parser attempts to nest inside each other before failing (no limit by default)
+    #[arg(long)]
+    max_depth: Option<usize>,
+}
+
+impl ParsingArgs {
+    fn options(self, memo: bool, recover: bool) -> ParseOptions {
+        ParseOptions {
+            rule: self.rule,
+            indentation: Indentation::New(self.indent.map_or(Indentation::Default().style, Indent::style), self.tab_width),
+            memo,
+            recover,
+            fuel: self.fuel,
+            max_depth: self.max_depth,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum Indent {
+    Any,
+    Spaces,
+    Tabs,
+    /// Whichever of tabs or spaces the first indented line uses
+    Detect,
+}
+
+impl Indent {
+    fn style(self) -> IndentStyle {
+        match self {
+            Indent::Any => IndentStyle::Any,
+            Indent::Spaces => IndentStyle::SpacesOnly,
+            Indent::Tabs => IndentStyle::TabsOnly,
+            Indent::Detect => IndentStyle::Detect,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum TraceOutputs {
+    Tree,
+    Json,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum GrammarOutputs {
+    Ebnf,
+    Dot,
+    Json,
+}
+
+fn read_input(input: Option<Vec<String>>, file: Option<String>) -> String {
+    match input {
+        None => {
+            if let Some(file) = file {
+                std::fs::read_to_string(file).unwrap()
+            } else {
+                panic!("No input provided and no file path provided.")
+            }
+        }
+        Some(input) => input.join(" "),
+    }
+}
+
+/// The parser for the named rule; or a source file's by default.
+/// - Exits with the available rule names if there's no rule with the given name.
+fn get_rule(grammar: &Grammar, rule: Option<String>) -> Box<dyn Parser> {
+    match rule {
+        Some(rule) => grammar.get_by_name(&rule).unwrap_or_else(|| {
+            let mut names: Vec<&str> = grammar.names().collect();
+            names.sort();
+            eprintln!("Unknown grammar rule: {}\nAvailable rules: {}", rule, names.join(", "));
+            std::process::exit(2);
+        }),
+        None => source::Parser.get(),
+    }
+}
+
+/// One message for a failure to match anything, or to match any of a choice's alternatives; from what was expected where parsing got the farthest.
+fn failure_message(result: &Parsed, cursor: &Cursor, input: &str) -> Option<String> {
+    let farthest = match result {
+        Parsed::Fail(None) => cursor.farthest(),
+        Parsed::Fail(Some(error)) if error.tag(CHOICE_TAG) => error.farthest.as_ref().or(cursor.farthest()),
+        _ => return None,
+    }?;
+
+    Some(farthest.describe(input, cursor.source_map()))
+}
+
+/// The location and message of an error; described by its farthest expectation if it's a choice's.
+fn error_message(error: &Error, cursor: &Cursor, input: &str) -> String {
+    match &error.farthest {
+        Some(farthest) if error.tag(CHOICE_TAG) => format!(" {}", farthest.describe(input, cursor.source_map())),
+        _ => {
+            let (line, column) = cursor.source_map().line_col_at(error.start);
+            format!("{}:{}: {}", line, column, error.get_message())
+        }
+    }
+}
+
+/// How to parse each input.
+struct ParseOptions {
+    rule: Option<String>,
+    indentation: Indentation,
+    memo: bool,
+    recover: bool,
+    fuel: Option<usize>,
+    max_depth: Option<usize>,
+}
+
+/// How to write each result.
+struct OutputOptions {
+    to: Option<Outputs>,
+    data: Option<Data>,
+    positions: bool,
+}
+
+fn configure(cursor: &mut Cursor, options: &ParseOptions) {
+    if options.memo {
+        cursor.enable_memo();
+    }
+    if let Some(fuel) = options.fuel {
+        cursor.limit_fuel(fuel);
+    }
+    if let Some(depth) = options.max_depth {
+        cursor.limit_depth(depth);
+    }
+}
+
+/// A cursor over the input; with the context of the file it was read from, if any.
+fn open_cursor<'s>(input: &'s str, path: Option<&str>, options: &ParseOptions) -> Cursor<'s> {
+    let ctx = match path {
+        Some(path) => Context::new_from_path(path),
+        None => Context::new_empty(),
+    };
+
+    let mut cursor = Cursor::New_With(input, ctx.with_indentation(options.indentation));
+    configure(&mut cursor, options);
+    cursor
+}
+
+fn parse_with(parser: &dyn Parser, cursor: &mut Cursor, options: &ParseOptions) -> (Parsed, Vec<Error>) {
+    if options.recover {
+        parser.parse_with_recovery_at(cursor)
+    } else {
+        (parser.parse_at(cursor), Vec::new())
+    }
+}
+
+fn render(output: &Parsed, errors: &[Error], input: &str, options: &OutputOptions, recover: bool) -> String {
+    match options.to {
+        None | Some(Outputs::Debug) => {
+            if recover {
+                format!("{:#?}\n{:#?}\n", output, errors)
+            } else {
+                format!("{:#?}\n", output)
+            }
+        }
+        Some(Outputs::Json) => {
+            let mut json = if recover {
+                serde_json::json!({
+                    "result": output,
+                    "errors": errors,
+                })
+            } else {
+                serde_json::to_value(output).unwrap()
+            };
+
+            if options.positions {
+                SourceMap::New(input).annotate_json(&mut json);
+            }
+
+            format!("{}\n", serde_json::to_string_pretty(&json).unwrap())
+        }
+        Some(Outputs::Sexp) => {
+            let format = match options.data {
+                Some(Data::Less) => SFormat::Less(),
+                Some(Data::Default) | None => SFormat::Default(),
+                Some(Data::Full) => SFormat::Full(input),
+            };
+
+            let mut result = format!("{}\n", output.to_sexp_str_with(format.clone()));
+            for error in errors {
+                result.push_str(&format!("{}\n", error.to_sexp_str_with(Some(format.clone()))));
+            }
+
+            result
+        }
+    }
+}
+
+/// The outcome of parsing one file in a folder; or of reading one of its subfolders.
+struct FileResult {
+    path: PathBuf,
+    /// The location and message of each error found; if any.
+    /// - Files or folders that couldn't be read, or whose result couldn't be written, fail with the I/O error instead.
+    errors: Vec<String>,
+}
+
+/// Collects the source files in the folder and its subfolders; skipping hidden ones, symlinked folders, and any of an unknown type.
+/// - Folders that can't be read fail with the I/O error.
+fn collect_files(folder: &Path, files: &mut Vec<PathBuf>, unreadable: &mut Vec<FileResult>, skipped: &mut usize) {
+    let mut entries: Vec<PathBuf> = match std::fs::read_dir(folder) {
+        Ok(entries) => entries.filter_map(|entry| entry.ok().map(|entry| entry.path())).collect(),
+        Err(error) => {
+            unreadable.push(FileResult {
+                path: folder.to_path_buf(),
+                errors: vec![format!(" Couldn't read the folder: {}", error)],
+            });
+            return;
+        }
+    };
+    entries.sort();
+
+    for path in entries {
+        let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+        if name.starts_with('.') {
+            continue;
+        } else if path.is_dir() {
+            // a symlink could point back up the tree; so linked folders aren't followed.
+            if std::fs::symlink_metadata(&path).map_or(false, |metadata| metadata.file_type().is_symlink()) {
+                *skipped += 1;
+            } else {
+                collect_files(&path, files, unreadable, skipped);
+            }
+        } else if fs::get_type_of(&name) == fs::Type::Unknown {
+            *skipped += 1;
+        } else {
+            files.push(path);
+        }
+    }
+}
+
+fn parse_file(
+    parser: &dyn Parser,
+    path: &Path,
+    root: &Path,
+    parsing: &ParseOptions,
+    output: &OutputOptions,
+    out: &Option<String>,
+) -> FileResult {
+    let input = match std::fs::read_to_string(path) {
+        Ok(input) => input,
+        Err(error) => {
+            return FileResult {
+                path: path.to_path_buf(),
+                errors: vec![format!(" Couldn't read the file: {}", error)],
+            }
+        }
+    };
+    let mut cursor = open_cursor(&input, Some(&path.to_string_lossy()), parsing);
+
+    let (result, errors) = parse_with(parser, &mut cursor, parsing);
+    let mut messages: Vec<String> = Vec::new();
+    match failure_message(&result, &cursor, &input) {
+        Some(message) => messages.push(format!(" {}", message)),
+        None => {
+            if let Parsed::Fail(Some(error)) = &result {
+                messages.push(error_message(error, &cursor, &input));
+            }
+        }
+    }
+    for error in &errors {
+        messages.push(error_message(error, &cursor, &input));
+    }
+
+    if let Some(out) = out {
+        let extension = match output.to {
+            None | Some(Outputs::Debug) => "txt",
+            Some(Outputs::Json) => "json",
+            Some(Outputs::Sexp) => "sexp",
+        };
+        let relative = path.strip_prefix(root).unwrap_or(path);
+        let target = Path::new(out).join(format!("{}.{}", relative.to_string_lossy(), extension));
+        let written = match target.parent() {
+            Some(parent) => std::fs::create_dir_all(parent),
+            None => Ok(()),
+        }
+        .and_then(|_| std::fs::write(&target, render(&result, &errors, &input, output, parsing.recover)));
+        if let Err(error) = written {
+            messages.push(format!(" Couldn't write the result to {}: {}", target.display(), error));
+        }
+    }
+
+    FileResult {
+        path: path.to_path_buf(),
+        errors: messages,
+    }
+}
+
+/// Parses every source file in the folder on a pool of threads; printing each file's result and a summary, and returning if they all passed.
+fn parse_folder(
+    parser: &dyn Parser,
+    folder: &str,
+    parsing: &ParseOptions,
+    output: &OutputOptions,
+    out: Option<String>,
+    jobs: Option<usize>,
+) -> bool {
+    let start = std::time::Instant::now();
+    let root = Path::new(folder);
+    let mut files = Vec::new();
+    let mut unreadable = Vec::new();
+    let mut skipped = 0;
+    collect_files(root, &mut files, &mut unreadable, &mut skipped);
+
+    // logging shares global state between parsers; so they can't run on more than one thread with it on.
+    let jobs = if cfg!(feature = "log") {
+        1
+    } else {
+        jobs.unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |n| n.get())).max(1)
+    };
+
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<FileResult>>> = Mutex::new((0..files.len()).map(|_| None).collect());
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.min(files.len()) {
+            std::thread::Builder::new()
+                .stack_size(PARSER_STACK_SIZE)
+                .spawn_scoped(scope, || loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    if index >= files.len() {
+                        break;
+                    }
+
+                    let result = parse_file(parser, &files[index], root, parsing, output, &out);
+                    results.lock().unwrap()[index] = Some(result);
+                })
+                .unwrap();
+        }
+    });
+
+    let results: Vec<FileResult> = results.into_inner().unwrap().into_iter().flatten().collect();
+    for folder in &unreadable {
+        for error in &folder.errors {
+            println!("✘ {}:{}", folder.path.display(), error);
+        }
+    }
+
+    let mut failed = 0;
+    for result in &results {
+        if result.errors.is_empty() {
+            println!("✔ {}", result.path.display());
+        } else {
+            failed += 1;
+            for error in &result.errors {
+                println!("✘ {}:{}", result.path.display(), error);
+            }
+        }
+    }
+
+    println!(
+        "Parsed {} files in {:.2}s with {} jobs: {} passed, {} failed, {} skipped.",
+        results.len(),
+        start.elapsed().as_secs_f64(),
+        jobs,
+        results.len() - failed,
+        failed,
+        skipped
+    );
+
+    failed == 0 && unreadable.is_empty()
+}
+
+/// The stack size of the threads parsing a folder; the same as the main thread's, so deep inputs parse the same as a single file.
+const PARSER_STACK_SIZE: usize = 8 * 1024 * 1024;
+
 fn main() {
     if cfg!(feature = "test") {
         let input = std::env::args().collect::<Vec<String>>();
@@ -101,11 +582,12 @@ fn main() {
             panic_on_fail,
             test_types,
             test_tags,
+            random_edits: args.edits,
         };
 
         println!("Running Tests with Settings: {:?}", &settings);
-        astra::parser::init_all();
-        tests::run_all_with_settings(&settings);
+        let grammar = Grammar::Default();
+        tests::run_all_with_settings(&grammar, &settings);
     } else {
         let input = std::env::args().collect::<Vec<String>>();
         if log::IS_VVV {
@@ -122,44 +604,293 @@ fn main() {
                 to,
                 data,
                 file,
-                out: _,
+                out,
+                jobs,
+                memo,
+                recover,
+                positions,
+                parsing,
+                lossless,
+                select,
             } => {
-                let input: String = match input {
-                    None => {
-                        if let Some(file) = file {
-                            std::fs::read_to_string(file)
-                                .unwrap()
-                                .lines()
-                                .map(|s| s.to_string())
-                                .collect()
-                        } else {
-                            panic!("No input provided and no file path provided.")
-                        }
-                    }
-                    Some(input) => input.join(" "),
+                let parsing = parsing.options(memo, recover);
+                let rendering = OutputOptions {
+                    to,
+                    data,
+                    positions,
                 };
 
-                let output = astra::parser::tokens::source::Parser::Parse(&input);
+                // an unknown rule is reported once; instead of by each thread.
+                let parser = get_rule(&Grammar::Default(), parsing.rule.clone());
+                if let (None, Some(folder)) = (&input, &file) {
+                    if Path::new(folder).is_dir() {
+                        let passed = parse_folder(&*parser, folder, &parsing, &rendering, out.clone(), jobs);
+                        std::process::exit(if passed { 0 } else { 1 });
+                    }
+                }
 
-                match to {
-                    None | Some(Outputs::Debug) => {
-                        println!("{:#?}", output);
+                let path = if input.is_none() { file.clone() } else { None };
+                let input = read_input(input, file);
+
+                let mut cursor = open_cursor(&input, path.as_deref(), &parsing);
+                let (output, errors) = parse_with(&*parser, &mut cursor, &parsing);
+                let passed = matches!(output, Parsed::Pass(_)) && errors.is_empty();
+                if let Some(stats) = cursor.memo_stats() {
+                    eprintln!(
+                        "Memo: {} hits, {} misses, {} entries",
+                        stats.hits, stats.misses, stats.entries
+                    );
+                }
+
+                if let Some(message) = failure_message(&output, &cursor, &input) {
+                    eprintln!("{}", message);
+                    std::process::exit(1);
+                }
+
+                if let Some(selector) = select {
+                    if let Parsed::Pass(token) = &output {
+                        match token.select(&selector) {
+                            Ok(matches) => {
+                                for found in matches {
+                                    match to {
+                                        Some(Outputs::Json) => println!(
+                                            "{}",
+                                            serde_json::json!({
+                                                "path": found.path.to_string(),
+                                                "token": found.token,
+                                            })
+                                        ),
+                                        _ => println!(
+                                            "{} => {}",
+                                            found.path,
+                                            found.token.to_sexp_str_with(Some(SFormat::Less()))
+                                        ),
+                                    }
+                                }
+                            }
+                            Err(error) => {
+                                eprintln!("{}", error);
+                                std::process::exit(1);
+                            }
+                        }
+
+                        return;
                     }
-                    Some(Outputs::Json) => {
-                        println!("{}", serde_json::to_string_pretty(&output).unwrap());
+                }
+
+                if lossless {
+                    match &output {
+                        Parsed::Pass(token) => {
+                            let cst = Cst::Of(token, &input);
+                            match to {
+                                None | Some(Outputs::Debug) => println!("{:#?}", cst),
+                                Some(Outputs::Json) => {
+                                    println!("{}", serde_json::to_string_pretty(&cst).unwrap())
+                                }
+                                Some(Outputs::Sexp) => println!("{}", cst.to_sexp_str()),
+                            }
+                        }
+                        Parsed::Fail(_) => println!("{:#?}", output),
                     }
-                    Some(Outputs::Sexp) => {
+
+                    return;
+                }
+
+                let rendered = render(&output, &errors, &input, &rendering, recover);
+                match out {
+                    Some(out) => std::fs::write(out, rendered).unwrap(),
+                    None => print!("{}", rendered),
+                }
+                if !passed {
+                    std::process::exit(1);
+                }
+            }
+            Commands::Trace {
+                input,
+                to,
+                file,
+                parsing,
+            } => {
+                let parsing = parsing.options(false, false);
+                let parser = get_rule(&Grammar::Default(), parsing.rule.clone());
+                let path = if input.is_none() { file.clone() } else { None };
+                let input = read_input(input, file);
+
+                let mut cursor = open_cursor(&input, path.as_deref(), &parsing);
+                cursor.enable_trace();
+
+                let output = parser.parse_at(&mut cursor);
+                let trace = cursor.take_trace().expect("tracing was enabled on the cursor");
+                match to {
+                    None | Some(TraceOutputs::Tree) => {
+                        print!("{}", trace.to_tree_str());
                         println!(
-                            "{}",
-                            output.to_sexp_str_with(match data {
-                                Some(Data::Less) => SFormat::Less(),
-                                Some(Data::Default) | None => SFormat::Default(),
-                                Some(Data::Full) => SFormat::Full(&input),
-                            })
+                            "{} events, {} backtracks => {}",
+                            trace.len(),
+                            trace.backtracks(),
+                            output.get_name()
                         );
                     }
+                    Some(TraceOutputs::Json) => println!("{}", trace.to_json()),
+                }
+            }
+            Commands::Fmt {
+                files,
+                check,
+                in_place,
+            } => {
+                let mut failed = false;
+                for path in files {
+                    let source = match std::fs::read_to_string(&path) {
+                        Ok(source) => source,
+                        Err(error) => {
+                            eprintln!("{}: {}", path, error);
+                            failed = true;
+                            continue;
+                        }
+                    };
+                    let formatted = match format::format(&source, Context::new_from_path(&path)) {
+                        Ok(formatted) => formatted,
+                        Err(error) => {
+                            eprintln!("{}: {}", path, error);
+                            failed = true;
+                            continue;
+                        }
+                    };
+
+                    if check {
+                        if formatted != source {
+                            println!("{}: not formatted", path);
+                            failed = true;
+                        }
+                    } else if in_place {
+                        if formatted != source {
+                            if let Err(error) = std::fs::write(&path, formatted) {
+                                eprintln!("{}: {}", path, error);
+                                failed = true;
+                                continue;
+                            }
+                        }
+                    } else {
+                        print!("{}", formatted);
+                    }
+                }
+
+                if failed {
+                    std::process::exit(1);
+                }
+            }
+            Commands::Grammar { to, out } => {
+                let grammar = Grammar::Default();
+                let output = match to {
+                    None | Some(GrammarOutputs::Ebnf) => grammar.to_ebnf(),
+                    Some(GrammarOutputs::Dot) => grammar.to_dot(),
+                    Some(GrammarOutputs::Json) => {
+                        serde_json::to_string_pretty(&grammar.to_railroad_json()).unwrap()
+                    }
+                };
+
+                match out {
+                    Some(out) => std::fs::write(out, output).unwrap(),
+                    None => print!("{}", output),
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod file_tests {
+    use super::*;
+
+    fn _options() -> (ParseOptions, OutputOptions) {
+        (
+            ParseOptions {
+                rule: None,
+                indentation: Indentation::Default(),
+                memo: false,
+                recover: false,
+                fuel: None,
+                max_depth: None,
+            },
+            OutputOptions {
+                to: None,
+                data: None,
+                positions: false,
+            },
+        )
+    }
+
+    #[test]
+    fn unreadable_files_fail_instead_of_panicking() {
+        let folder = std::env::temp_dir().join(format!("astra-unreadable-{}", std::process::id()));
+        std::fs::create_dir_all(&folder).unwrap();
+        let path = folder.join("invalid.astra");
+        std::fs::write(&path, [0xff, 0xfe, 0xfd]).unwrap();
+
+        let (parsing, output) = _options();
+        let result = parse_file(&*source::Parser.get(), &path, &folder, &parsing, &output, &None);
+        std::fs::remove_dir_all(&folder).unwrap();
+
+        assert_eq!(result.errors.len(), 1);
+        assert!(result.errors[0].contains("Couldn't read the file"));
+    }
+
+    #[test]
+    fn unwritable_results_fail_instead_of_panicking() {
+        let folder = std::env::temp_dir().join(format!("astra-unwritable-{}", std::process::id()));
+        std::fs::create_dir_all(&folder).unwrap();
+        let path = folder.join("valid.astra");
+        std::fs::write(&path, "a: 1").unwrap();
+        // a file where the output folder should be.
+        let out = folder.join("out");
+        std::fs::write(&out, "").unwrap();
+
+        let (parsing, output) = _options();
+        let result = parse_file(
+            &*source::Parser.get(),
+            &path,
+            &folder,
+            &parsing,
+            &output,
+            &Some(out.to_string_lossy().to_string()),
+        );
+        std::fs::remove_dir_all(&folder).unwrap();
+
+        assert!(result.errors.iter().any(|error| error.contains("Couldn't write the result")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlinked_folders_are_skipped() {
+        let folder = std::env::temp_dir().join(format!("astra-symlinked-{}", std::process::id()));
+        std::fs::create_dir_all(folder.join("sub")).unwrap();
+        std::fs::write(folder.join("sub").join("valid.axa"), "a: 1").unwrap();
+        // a link back up to the root; which would be followed forever.
+        std::os::unix::fs::symlink(&folder, folder.join("sub").join("loop")).unwrap();
+
+        let mut files = Vec::new();
+        let mut unreadable = Vec::new();
+        let mut skipped = 0;
+        collect_files(&folder, &mut files, &mut unreadable, &mut skipped);
+        std::fs::remove_dir_all(&folder).unwrap();
+
+        assert_eq!(files, vec![folder.join("sub").join("valid.axa")]);
+        assert!(unreadable.is_empty());
+        assert_eq!(skipped, 1);
+    }
+
+    #[test]
+    fn unreadable_folders_fail_instead_of_panicking() {
+        let folder = std::env::temp_dir().join(format!("astra-missing-{}", std::process::id()));
+
+        let mut files = Vec::new();
+        let mut unreadable = Vec::new();
+        let mut skipped = 0;
+        collect_files(&folder, &mut files, &mut unreadable, &mut skipped);
+
+        assert!(files.is_empty());
+        assert_eq!(unreadable.len(), 1);
+        assert!(unreadable[0].errors[0].contains("Couldn't read the folder"));
+    }
+}
@@ -1,13 +1,20 @@
-use super::fs::{get_lang, get_type, File};
+use super::{
+    fs::{get_lang, get_type_of, File},
+    indents::Indentation,
+};
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Language {
     StruX,
     ProX,
     BloX,
 }
 
+#[derive(Clone)]
 pub struct Context {
     pub lang: Language,
     pub file: Option<File>,
+    /// How the indentation of each line is measured and validated.
+    pub indentation: Indentation,
 }
 
 impl Context {
@@ -15,17 +22,23 @@ impl Context {
         Context {
             lang: Language::StruX,
             file: None,
+            indentation: Indentation::Default(),
         }
     }
 
     pub fn new_for(lang: Language) -> Context {
-        Context { lang, file: None }
+        Context {
+            lang,
+            file: None,
+            indentation: Indentation::Default(),
+        }
     }
 
     pub fn new_from(file: File) -> Context {
         Context {
             lang: get_lang(&file.kind),
             file: Some(file),
+            indentation: Indentation::Default(),
         }
     }
 
@@ -33,12 +46,13 @@ impl Context {
         let file = File {
             path: name.to_string(),
             name: name.to_string(),
-            kind: get_type(name),
+            kind: get_type_of(name),
         };
 
         Context {
             lang: get_lang(&file.kind),
             file: Some(file),
+            indentation: Indentation::Default(),
         }
     }
 
@@ -47,12 +61,18 @@ impl Context {
         let file = File {
             path: path.to_string(),
             name: name.to_string(),
-            kind: get_type(name),
+            kind: get_type_of(name),
         };
 
         Context {
             lang: get_lang(&file.kind),
             file: Some(file),
+            indentation: Indentation::Default(),
         }
     }
+
+    pub fn with_indentation(mut self, indentation: Indentation) -> Context {
+        self.indentation = indentation;
+        self
+    }
 }
@@ -0,0 +1,339 @@
+use serde::Serialize;
+
+use super::results::{node::Node, token::Token};
+
+/// The kind of whitespace a piece of trivia is.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TriviaKind {
+    /// A line break; `\n` or `\r\n`.
+    Newline,
+    /// The whitespace at the start of a line.
+    Indent,
+    /// Any other whitespace; like the spaces between tokens or at the end of a line.
+    Space,
+}
+
+/// Whitespace attached to a leaf of a concrete syntax tree.
+#[derive(PartialEq, Eq, Debug, Clone, Serialize)]
+pub struct Trivia {
+    pub kind: TriviaKind,
+    pub start: usize,
+    pub text: String,
+}
+
+/// What the text of a leaf is.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LeafKind {
+    /// The text of a token without any children.
+    Token,
+    /// Text of a token that isn't part of any of its children; like symbols and delimiters.
+    Text,
+    /// The empty leaf at the end of the input; holding any trivia after the last token.
+    End,
+}
+
+/// A leaf of a concrete syntax tree; with the trivia before and after it.
+/// - Leading trivia is any whitespace since the previous leaf's trailing trivia.
+/// - Trailing trivia is the whitespace after the leaf; up to and including the end of its line.
+#[derive(PartialEq, Eq, Debug, Clone, Serialize)]
+pub struct Leaf {
+    pub kind: LeafKind,
+    /// The name of the token the leaf's text belongs to.
+    pub name: String,
+    pub tags: Vec<String>,
+    /// The char index the leaf's own text starts at.
+    pub start: usize,
+    pub text: String,
+    pub leading: Vec<Trivia>,
+    pub trailing: Vec<Trivia>,
+}
+
+impl Leaf {
+    /// The leaf's text with all of its trivia.
+    pub fn full_text(&self) -> String {
+        let mut result = String::new();
+        for trivia in &self.leading {
+            result.push_str(&trivia.text);
+        }
+        result.push_str(&self.text);
+        for trivia in &self.trailing {
+            result.push_str(&trivia.text);
+        }
+
+        result
+    }
+}
+
+/// A part of a token in a concrete syntax tree.
+#[derive(PartialEq, Eq, Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum Element {
+    Node(CstNode),
+    /// The index of a leaf in the tree's leaves.
+    Leaf(usize),
+}
+
+/// A token with children in a concrete syntax tree.
+#[derive(PartialEq, Eq, Debug, Clone, Serialize)]
+pub struct CstNode {
+    pub name: String,
+    pub tags: Vec<String>,
+    pub children: Vec<Element>,
+}
+
+/// A lossless concrete syntax tree; where every char of the source belongs to exactly one leaf.
+/// - Concatenating the full text of the leaves, in order, reproduces the source.
+/// - Indent tokens are not kept as nodes; their whitespace becomes trivia instead.
+#[derive(PartialEq, Eq, Debug, Clone, Serialize)]
+pub struct Cst {
+    pub root: CstNode,
+    /// Every leaf in the tree; in source order.
+    pub leaves: Vec<Leaf>,
+}
+
+impl Cst {
+    /// Builds the concrete syntax tree for a token parsed from the given source.
+    #[allow(non_snake_case)]
+    pub fn Of(token: &Token, source: &str) -> Cst {
+        let mut builder = Builder {
+            chars: source.chars().collect(),
+            leaves: Vec::new(),
+            pending: Vec::new(),
+            trailing_open: false,
+        };
+
+        let len = builder.chars.len();
+        let mut root = builder.node(token, 0, len);
+        let end = builder.leaf(LeafKind::End, &token.name, Vec::new(), len, len);
+        root.children.push(Element::Leaf(end));
+
+        Cst {
+            root,
+            leaves: builder.leaves,
+        }
+    }
+
+    /// Writes the tree as an s-expression; with each leaf's text and trivia quoted.
+    pub fn to_sexp_str(&self) -> String {
+        self._node_to_sexp_str(&self.root, 0)
+    }
+
+    fn _node_to_sexp_str(&self, node: &CstNode, depth: usize) -> String {
+        let mut result = format!("({}", node.name);
+        for tag in &node.tags {
+            result.push_str(&format!(" #{}", tag));
+        }
+
+        for child in &node.children {
+            result.push('\n');
+            result.push_str(&"\t".repeat(depth + 1));
+            match child {
+                Element::Node(node) => result.push_str(&self._node_to_sexp_str(node, depth + 1)),
+                Element::Leaf(index) => {
+                    let leaf = &self.leaves[*index];
+                    let leading: String = leaf.leading.iter().map(|t| format!("{:?} ", t.text)).collect();
+                    let trailing: String = leaf.trailing.iter().map(|t| format!(" {:?}", t.text)).collect();
+                    result.push_str(&format!(
+                        "[{}{} {}{:?}{}]",
+                        match leaf.kind {
+                            LeafKind::Token => "",
+                            LeafKind::Text => "text of ",
+                            LeafKind::End => "end of ",
+                        },
+                        leaf.name,
+                        leading,
+                        leaf.text,
+                        trailing
+                    ));
+                }
+            }
+        }
+
+        result.push(')');
+        result
+    }
+}
+
+/// The source the tree was built from.
+impl std::fmt::Display for Cst {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for leaf in &self.leaves {
+            write!(f, "{}", leaf.full_text())?;
+        }
+
+        Ok(())
+    }
+}
+
+struct Builder {
+    chars: Vec<char>,
+    leaves: Vec<Leaf>,
+    /// Trivia waiting to lead the next leaf.
+    pending: Vec<Trivia>,
+    /// If trivia can still trail the last leaf; which it can until the end of its line.
+    trailing_open: bool,
+}
+
+impl Builder {
+    /// Builds the node for the token; covering only the chars from `from` up to `to`.
+    fn node(&mut self, token: &Token, from: usize, to: usize) -> CstNode {
+        let mut node = CstNode {
+            name: token.name.clone(),
+            tags: _sorted_tags(token),
+            children: Vec::new(),
+        };
+
+        let mut children: Vec<&Token> = token.children.iter().filter(|child| !child.tag("indent")).collect();
+        children.sort_by_key(|child| child.start);
+
+        let mut pos = from;
+        for child in children {
+            // children may overlap their siblings or stick out of their parent; so only the uncovered part is used.
+            let start = child.start.max(pos);
+            let end = (child.end + 1).min(to);
+            if start >= end {
+                continue;
+            }
+
+            self.gap(&token.name, pos, start, &mut node.children);
+            if child.children.is_empty() {
+                let leaf = self.leaf(LeafKind::Token, &child.name, _sorted_tags(child), start, end);
+                node.children.push(Element::Leaf(leaf));
+            } else {
+                node.children.push(Element::Node(self.node(child, start, end)));
+            }
+            pos = end;
+        }
+
+        self.gap(&token.name, pos, to, &mut node.children);
+        node
+    }
+
+    /// Splits the chars not covered by a token's children into trivia, and leaves for any other text.
+    fn gap(&mut self, name: &str, from: usize, to: usize, children: &mut Vec<Element>) {
+        let mut pos = from;
+        while pos < to {
+            let start = pos;
+            if self.chars[pos].is_whitespace() {
+                while pos < to && self.chars[pos].is_whitespace() {
+                    pos = self.trivia(pos, to);
+                }
+            } else {
+                while pos < to && !self.chars[pos].is_whitespace() {
+                    pos += 1;
+                }
+
+                let leaf = self.leaf(LeafKind::Text, name, Vec::new(), start, pos);
+                children.push(Element::Leaf(leaf));
+            }
+        }
+    }
+
+    /// Reads one piece of trivia starting at `pos`; returning where it ends.
+    fn trivia(&mut self, pos: usize, to: usize) -> usize {
+        let (kind, end) = match self.chars[pos] {
+            '\n' => (TriviaKind::Newline, pos + 1),
+            '\r' if pos + 1 < to && self.chars[pos + 1] == '\n' => (TriviaKind::Newline, pos + 2),
+            _ => {
+                let mut end = pos;
+                while end < to && self.chars[end].is_whitespace() && self.chars[end] != '\n' && self.chars[end] != '\r' {
+                    end += 1;
+                }
+
+                let at_line_start = pos == 0 || self.chars[pos - 1] == '\n';
+                (
+                    if at_line_start { TriviaKind::Indent } else { TriviaKind::Space },
+                    end.max(pos + 1),
+                )
+            }
+        };
+
+        let trivia = Trivia {
+            kind,
+            start: pos,
+            text: self.chars[pos..end].iter().collect(),
+        };
+
+        if self.trailing_open {
+            self.leaves.last_mut().unwrap().trailing.push(trivia);
+            if kind == TriviaKind::Newline {
+                self.trailing_open = false;
+            }
+        } else {
+            self.pending.push(trivia);
+        }
+
+        end
+    }
+
+    fn leaf(&mut self, kind: LeafKind, name: &str, tags: Vec<String>, start: usize, end: usize) -> usize {
+        self.leaves.push(Leaf {
+            kind,
+            name: name.to_string(),
+            tags,
+            start,
+            text: self.chars[start..end].iter().collect(),
+            leading: std::mem::take(&mut self.pending),
+            trailing: Vec::new(),
+        });
+        self.trailing_open = true;
+
+        self.leaves.len() - 1
+    }
+}
+
+fn _sorted_tags(token: &Token) -> Vec<String> {
+    let mut tags: Vec<String> = token.tags().iter().cloned().collect();
+    tags.sort();
+    tags
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{cursor::Cursor, results::parsed::Parsed, tokens::source, Parser as _};
+
+    /// Parses the source as a source file with recovery; since the unit tests can't recover from errors.
+    fn parse(source: &str) -> Token {
+        let mut cursor = Cursor::New(source);
+        let (result, errors) = source::Parser.parse_with_recovery_at(&mut cursor);
+        assert!(
+            !errors.is_empty(),
+            "Expected errors to recover from in {:?}",
+            source
+        );
+
+        match result {
+            Parsed::Pass(token) => token,
+            Parsed::Fail(error) => panic!("Expected {:?} to parse; got {:?}", source, error),
+        }
+    }
+
+    fn assert_round_trips(source: &str) {
+        let cst = Cst::Of(&parse(source), source);
+        assert_eq!(cst.to_string(), source);
+    }
+
+    #[test]
+    fn round_trips_trivia_only_input() {
+        assert_round_trips("  \n\t\n");
+        assert_round_trips("\n");
+    }
+
+    #[test]
+    fn round_trips_error_recovered_input() {
+        assert_round_trips("a: (\nb: 2");
+        assert_round_trips("a:\n\tb: (1\n\tc: 2\n");
+    }
+
+    #[test]
+    fn trivia_only_input_is_all_trivia_of_the_end_leaf() {
+        let source = "  \n\t\n";
+        let cst = Cst::Of(&parse(source), source);
+        let end = cst.leaves.last().unwrap();
+        assert_eq!(end.kind, LeafKind::End);
+        assert_eq!(end.full_text(), source);
+    }
+}
@@ -1,8 +1,16 @@
+use std::cell::{Cell, OnceCell, RefCell};
+
 use crate::{
     parser::{
         context::{Context, Language},
+        farthest::{Expectation, Farthest},
         fs,
-        indents::Indents,
+        indents::{IndentStyle, Indents},
+        limits::Limits,
+        memo::{self, Memo},
+        results::{error::Error, parsed::Parsed, token::Token},
+        source_map::SourceMap,
+        trace::{Event, Trace},
     },
     utils::log,
 };
@@ -10,39 +18,63 @@ use crate::{
 #[cfg(feature = "log")]
 use crate::utils::ansi::Color;
 
-pub struct Cursor {
-    src: Vec<char>,
+/// Walks a borrowed source by byte offset; while tracking positions as char indices.
+pub struct Cursor<'s> {
+    src: &'s str,
+    /// The byte offset of the current char.
+    byte: usize,
+    examined: Cell<Option<(usize, usize)>>,
+    source_map: OnceCell<SourceMap>,
+    /// The byte offset of every `CHECKPOINT_CHARS`th char; found as far positions are looked up.
+    checkpoints: RefCell<Vec<usize>>,
     indents: Indents,
     pos: usize,
     ctx: Context,
-    state: Vec<State>,
-    #[cfg(feature = "log")]
-    pub(crate) _src: String,
+    /// The saved states; with the byte offset of each, so restoring doesn't need to find it again.
+    state: Vec<(State, usize)>,
+    memo: Option<Memo>,
+    recovered: Option<Vec<Error>>,
+    trace: Option<Trace>,
+    limits: Limits,
+    steps: usize,
+    depth: usize,
+    exceeded: Option<Error>,
+    farthest: Option<Farthest>,
+    /// The chars that close each delimited expression being read, and the indents it was opened at; innermost last.
+    closers: Vec<(&'static [char], Indents)>,
 }
 
+/// How many chars away from the current position `byte_of` decodes; before starting from a checkpoint instead.
+const NEARBY_CHARS: usize = 256;
+
+/// How many chars apart the byte offsets kept for finding far positions are.
+const CHECKPOINT_CHARS: usize = 64;
+
 pub enum Branch {
     Continue,
     Revert,
 }
 
+#[derive(Clone)]
 pub struct State {
     pub pos: usize,
     pub indents: Indents,
+    pub recovered: usize,
 }
 
-impl Cursor {
+impl<'s> Cursor<'s> {
     #[allow(non_snake_case)]
-    pub fn New(source: &str) -> Cursor {
+    pub fn New(source: &'s str) -> Cursor<'s> {
         Cursor::New_With(source, Context::new_empty())
     }
 
     #[allow(non_snake_case)]
-    pub fn New_For(source: &str, lang: Language) -> Cursor {
+    pub fn New_For(source: &'s str, lang: Language) -> Cursor<'s> {
         Cursor::New_With(source, Context::new_for(lang))
     }
 
     #[allow(non_snake_case)]
-    pub fn New_With(source: &str, ctx: Context) -> Cursor {
+    pub fn New_With(source: &'s str, ctx: Context) -> Cursor<'s> {
         log::color!("CURSOR", Color::BrightGreen);
         log::color!("TOKEN", Color::BrightBlue);
         log::color!("INDENT", Color::BrightWhite);
@@ -54,22 +86,56 @@ impl Cursor {
             &format!("Creating new cursor for input of length {}", source.len()),
         );
         log::info!(&["CURSOR", "INDENT", ":START"], " curr: 0");
-        let src: Vec<char> = (source.to_string() + "\0").chars().collect();
         Cursor {
             pos: 0,
-            src,
+            byte: 0,
+            src: source,
+            examined: Cell::new(None),
+            source_map: OnceCell::new(),
+            checkpoints: RefCell::new(vec![0]),
             ctx,
-            indents: Indents {
-                stack: Vec::new(),
-                is_reading: true,
-                curr: 0,
-            },
+            indents: Indents::New(),
             state: Vec::new(),
-            #[cfg(feature = "log")]
-            _src: source.to_string(),
+            memo: None,
+            recovered: None,
+            trace: None,
+            limits: Limits::Default(),
+            steps: 0,
+            depth: 0,
+            exceeded: None,
+            farthest: None,
+            closers: Vec::new(),
+        }
+    }
+
+    /// Starts reading within a delimited expression; like a string or closure, which any of the given chars close.
+    /// - Words and sentences end before a closer; so the expression that opened it can read it.
+    /// - Indentation isn't significant within it; see `is_delimited`.
+    pub fn open_delimited(&mut self, closers: &'static [char]) {
+        self.closers.push((closers, self.indents.clone()));
+    }
+
+    /// Stops reading within the innermost delimited expression; restoring the indents it was opened at.
+    pub fn close_delimited(&mut self) {
+        if let Some((_, indents)) = self.closers.pop() {
+            self.indents = indents;
         }
     }
 
+    /// If the cursor is within a delimited expression; where lines can be indented freely.
+    pub fn is_delimited(&self) -> bool {
+        !self.closers.is_empty()
+    }
+
+    /// If the char closes the innermost delimited expression being read.
+    pub fn is_closer(&self, c: char) -> bool {
+        self.closers.last().is_some_and(|(closers, _)| closers.contains(&c))
+    }
+
+    pub fn curr_is_closer(&self) -> bool {
+        !self.is_eof() && self.is_closer(self.curr())
+    }
+
     pub fn curr_indent(&self) -> usize {
         return self.indents.curr;
     }
@@ -82,10 +148,325 @@ impl Cursor {
         return &self.ctx;
     }
 
+    /// The full source being parsed.
+    pub fn source(&self) -> &'s str {
+        return self.src;
+    }
+
+    /// Maps positions in the source between char indices, bytes, UTF-16 and lines/columns.
+    pub fn source_map(&self) -> &SourceMap {
+        self.source_map.get_or_init(|| SourceMap::New(self.src))
+    }
+
     pub fn state(&self) -> State {
         State {
             pos: self.pos,
             indents: self.indents.clone(),
+            recovered: self.recovered().len(),
+        }
+    }
+
+    /// Turns on packrat memoization of parser results for this cursor.
+    pub fn enable_memo(&mut self) {
+        if self.memo.is_none() {
+            self.memo = Some(Memo::New());
+        }
+    }
+
+    pub fn is_memoized(&self) -> bool {
+        return self.memo.is_some();
+    }
+
+    /// The memo table's hit/miss counters; if memoization is enabled.
+    pub fn memo_stats(&self) -> Option<memo::Stats> {
+        return self.memo.as_ref().map(|memo| memo.stats());
+    }
+
+    pub(crate) fn take_memo(&mut self) -> Option<Memo> {
+        return self.memo.take();
+    }
+
+    pub(crate) fn use_memo(&mut self, memo: Memo) {
+        self.memo = Some(memo);
+    }
+
+    pub(crate) fn memo_key(&self, parser: &'static str) -> Option<memo::Key> {
+        match self.memo {
+            Some(_) => Some(memo::Key {
+                parser,
+                pos: self.pos,
+                indents: self.indents.clone(),
+                closers: self.closers.last().map(|(closers, _)| *closers),
+            }),
+            None => None,
+        }
+    }
+
+    pub(crate) fn recall(&mut self, key: &memo::Key) -> Option<(Parsed, State, Vec<Error>)> {
+        let entry = self.memo.as_mut()?.recall(key)?;
+        log::info!(
+            &["CURSOR", "MEMO", "HIT"],
+            &format!("{} @ {} ~> {}", key.parser, key.pos, entry.end.pos)
+        );
+
+        let examined = entry.examined;
+        let farthest = entry.farthest.clone();
+        let result = Some((
+            entry.result.clone(),
+            entry.end.clone(),
+            entry.recovered.clone(),
+        ));
+
+        if let Some((start, end)) = examined {
+            self.examine(start, end);
+        }
+
+        if let Some(farthest) = farthest {
+            self.farthest = Some(match self.farthest.take() {
+                Some(outer) => outer.merge(farthest),
+                None => farthest,
+            });
+        }
+
+        result
+    }
+
+    pub(crate) fn remember(
+        &mut self,
+        key: memo::Key,
+        result: &Parsed,
+        end: State,
+        since: usize,
+        examined: Option<(usize, usize)>,
+        farthest: Option<Farthest>,
+    ) {
+        let recovered = self.recovered()[since.min(self.recovered().len())..].to_vec();
+        if let Some(memo) = self.memo.as_mut() {
+            memo.remember(key, result.clone(), end, recovered, examined, farthest);
+        }
+    }
+
+    /// Starts tracking the range of source characters examined; returning the range tracked so far.
+    pub(crate) fn start_examining(&self) -> Option<(usize, usize)> {
+        return self.examined.take();
+    }
+
+    /// Stops tracking the range started by `start_examining`; returning it, and merging it back into the outer range.
+    pub(crate) fn stop_examining(&self, outer: Option<(usize, usize)>) -> Option<(usize, usize)> {
+        let inner = self.examined.get();
+        self.examined.set(outer);
+        if let Some((start, end)) = inner {
+            self.examine(start, end);
+        }
+
+        return inner;
+    }
+
+    #[inline]
+    fn examine(&self, start: usize, end: usize) {
+        // only memoized results need to know what they depended on.
+        if self.memo.is_none() {
+            return;
+        }
+
+        self.examined.set(Some(match self.examined.get() {
+            Some((min, max)) => (min.min(start), max.max(end)),
+            None => (start, end),
+        }));
+    }
+
+    /// What failed parser attempts expected, at the farthest position any of them reached.
+    pub fn farthest(&self) -> Option<&Farthest> {
+        return self.farthest.as_ref();
+    }
+
+    /// Records something a failed parser attempt expected at the given position.
+    pub(crate) fn expect(&mut self, pos: usize, expectation: Expectation) {
+        let expected = Farthest::New(pos, expectation);
+        self.farthest = Some(match self.farthest.take() {
+            Some(farthest) => farthest.merge(expected),
+            None => expected,
+        });
+    }
+
+    /// Starts collecting the expectations of a parser attempt on their own; returning those collected so far.
+    pub(crate) fn start_expecting(&mut self) -> Option<Farthest> {
+        return self.farthest.take();
+    }
+
+    /// Stops collecting the expectations started by `start_expecting`; returning them, and merging them back into the outer ones.
+    pub(crate) fn stop_expecting(&mut self, outer: Option<Farthest>) -> Option<Farthest> {
+        let inner = self.farthest.take();
+        self.farthest = match (outer, inner.clone()) {
+            (Some(outer), Some(inner)) => Some(outer.merge(inner)),
+            (outer, inner) => outer.or(inner),
+        };
+
+        return inner;
+    }
+
+    pub(crate) fn jump_to(&mut self, state: State) {
+        log::info!(
+            &["CURSOR", "JUMP"],
+            &format!("{} ~> {}", self.pos, state.pos)
+        );
+
+        self.move_to(state.pos);
+        self.indents = state.indents;
+    }
+
+    /// Turns on recording of parser attempts and backtracks into a trace.
+    pub fn enable_trace(&mut self) {
+        if self.trace.is_none() {
+            self.trace = Some(Trace::New());
+        }
+    }
+
+    pub fn is_tracing(&self) -> bool {
+        return self.trace.is_some();
+    }
+
+    pub fn trace(&self) -> Option<&Trace> {
+        return self.trace.as_ref();
+    }
+
+    pub fn take_trace(&mut self) -> Option<Trace> {
+        return self.trace.take();
+    }
+
+    pub(crate) fn record(&mut self, event: Event) {
+        if let Some(trace) = self.trace.as_mut() {
+            trace.push(event);
+        }
+    }
+
+    /// Limits the total number of parser attempts made with this cursor.
+    pub fn limit_fuel(&mut self, fuel: usize) {
+        self.limits.fuel = Some(fuel);
+    }
+
+    /// Limits how deeply parser attempts can be nested inside each other.
+    pub fn limit_depth(&mut self, depth: usize) {
+        self.limits.depth = Some(depth);
+    }
+
+    pub fn set_limits(&mut self, limits: Limits) {
+        self.limits = limits;
+    }
+
+    pub fn limits(&self) -> Limits {
+        return self.limits;
+    }
+
+    /// The number of parser attempts made so far.
+    pub fn steps(&self) -> usize {
+        return self.steps;
+    }
+
+    /// The number of parser attempts currently nested inside each other.
+    pub fn depth(&self) -> usize {
+        return self.depth;
+    }
+
+    /// The error for the limit that was exceeded; if parsing was cut short.
+    pub fn exceeded_limit(&self) -> Option<&Error> {
+        return self.exceeded.as_ref();
+    }
+
+    /// Counts a parser attempt; returning an error instead if it would exceed a limit.
+    /// - Once a limit is exceeded, every attempt after fails with the same error; so parsing ends instead of trying alternatives.
+    /// - Each call must be paired with a call to `exit`; even if it returns an error.
+    pub(crate) fn enter(&mut self) -> Option<Error> {
+        self.depth += 1;
+        if let Some(error) = &self.exceeded {
+            return Some(error.clone());
+        }
+
+        self.steps += 1;
+        self.exceeded = self.limits.check(self.steps, self.depth, self.pos);
+        #[cfg(feature = "v")]
+        if let Some(error) = &self.exceeded {
+            log::info!(&["CURSOR", "LIMIT"], &error.name);
+        }
+
+        return self.exceeded.clone();
+    }
+
+    pub(crate) fn exit(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+    }
+
+    /// Turns on error recovery; letting trees and entries skip past errors instead of failing.
+    pub fn enable_recovery(&mut self) {
+        if self.recovered.is_none() {
+            self.recovered = Some(Vec::new());
+        }
+    }
+
+    pub fn is_recovering(&self) -> bool {
+        return self.recovered.is_some();
+    }
+
+    /// All errors that have been recovered from so far.
+    pub fn recovered(&self) -> &[Error] {
+        match &self.recovered {
+            Some(errors) => errors,
+            None => &[],
+        }
+    }
+
+    pub fn take_recovered(&mut self) -> Vec<Error> {
+        match self.recovered.as_mut() {
+            Some(errors) => std::mem::take(errors),
+            None => Vec::new(),
+        }
+    }
+
+    /// Records the error, skips to the next line indented at or below `indent`, and returns an error token to take its place.
+    pub(crate) fn recover(&mut self, error: Error, indent: usize) -> Token {
+        log::info!(
+            &["CURSOR", "RECOVER"],
+            &format!("{} @ {}..{}", error.name, error.start, self.pos)
+        );
+
+        self.skip_to_indent(indent);
+        let token = error.to_token(self.prev_non_ws_pos());
+        if let Some(errors) = self.recovered.as_mut() {
+            errors.push(error);
+        }
+
+        token
+    }
+
+    pub(crate) fn append_recovered(&mut self, errors: Vec<Error>) {
+        if let Some(recovered) = self.recovered.as_mut() {
+            recovered.extend(errors);
+        }
+    }
+
+    /// Skips the rest of the current line, and any following lines that are blank or indented deeper than `indent`.
+    /// Stops on the newline before the next line indented at or below `indent`.
+    pub fn skip_to_indent(&mut self, indent: usize) {
+        loop {
+            self.skip_until(|c| c == '\n');
+            if self.is_eof() {
+                return;
+            }
+
+            let mut pos = self.pos + 1;
+            let mut width = 0;
+            while !self.eof_at(pos) && (self.at(pos) == ' ' || self.at(pos) == '\t') {
+                width += self.ctx.indentation.width_of(self.at(pos));
+                pos += 1;
+            }
+
+            if self.eof_at(pos) {
+                return;
+            } else if self.at(pos) != '\n' && width <= indent {
+                return;
+            }
+
+            self.skip();
         }
     }
 
@@ -116,20 +497,34 @@ impl Cursor {
         log::info!(&["CURSOR", "SAVE"], &format!("@ {}", self.pos));
 
         let state = self.state();
-        self.state.push(state);
+        self.state.push((state, self.byte));
+        if self.is_tracing() {
+            self.record(Event::Save { pos: self.pos });
+        }
 
         self.pos
     }
 
     pub(crate) fn restore(&mut self) -> usize {
-        let state = self.state.pop().unwrap();
+        let (state, byte) = self.state.pop().unwrap();
         log::info!(
             &["CURSOR", "RESTORE"],
             &format!("{} ~> {}", self.pos, state.pos),
         );
 
+        if self.is_tracing() {
+            self.record(Event::Restore {
+                from: self.pos,
+                to: state.pos,
+            });
+        }
+
         self.pos = state.pos;
+        self.byte = byte;
         self.indents = state.indents;
+        if let Some(errors) = self.recovered.as_mut() {
+            errors.truncate(state.recovered);
+        }
 
         self.pos
     }
@@ -138,6 +533,10 @@ impl Cursor {
         #[cfg(feature = "v")]
         let initial = self.pos;
         self.state.pop();
+        if self.is_tracing() {
+            self.record(Event::Pop { pos: self.pos });
+        }
+
         log::info!(
             &["CURSOR", "POP"],
             &format!("({} ~~~ {})", initial, self.pos)
@@ -150,7 +549,7 @@ impl Cursor {
 
     pub fn read(&mut self) -> char {
         #[cfg(feature = "v")]
-        let next = if self.pos >= self.src.len() - 2 {
+        let next = if self.eof_at(self.pos + 1) {
             '\0'
         } else {
             self.next()
@@ -166,8 +565,9 @@ impl Cursor {
             ),
         );
 
-        self._update_indents();
-        self.pos += 1;
+        let curr = self.curr();
+        self._update_indents(curr);
+        self._advance(curr);
 
         if self.is_eof() {
             log::info!(&["CURSOR", ":EOF"], "Reached end of file.");
@@ -177,6 +577,7 @@ impl Cursor {
         self.curr()
     }
 
+    #[inline]
     pub fn skip(&mut self) {
         log::info!(
             &["CURSOR", "SKIP"],
@@ -189,16 +590,73 @@ impl Cursor {
             ),
         );
 
-        self._update_indents();
-        self.pos += 1;
+        let curr = self.curr();
+        self._update_indents(curr);
+        self._advance(curr);
 
         if self.is_eof() {
             log::info!(&["CURSOR", ":EOF"], "Reached end of file.");
         }
     }
 
-    fn _update_indents(&mut self) {
-        match self.curr() {
+    #[inline]
+    fn _advance(&mut self, curr: char) {
+        if self.byte < self.src.len() {
+            self.pos += 1;
+            self.byte += curr.len_utf8();
+        }
+    }
+
+    /// Moves to the given char index.
+    fn move_to(&mut self, pos: usize) {
+        self.byte = self.byte_of(pos).unwrap_or(self.src.len());
+        self.pos = pos;
+    }
+
+    /// The byte offset of the given char index; if it's within (or at the end of) the source.
+    /// - Nearby indices are found by decoding chars from the current position; farther ones from the checkpoint before them.
+    fn byte_of(&self, pos: usize) -> Option<usize> {
+        if pos.abs_diff(self.pos) > NEARBY_CHARS {
+            let mut checkpoints = self.checkpoints.borrow_mut();
+            while checkpoints.len() <= pos / CHECKPOINT_CHARS {
+                let last = checkpoints[checkpoints.len() - 1];
+                match self.src[last..].char_indices().nth(CHECKPOINT_CHARS) {
+                    Some((offset, _)) => checkpoints.push(last + offset),
+                    None => break,
+                }
+            }
+
+            let checkpoint = (pos / CHECKPOINT_CHARS).min(checkpoints.len() - 1);
+            return self._byte_after(checkpoint * CHECKPOINT_CHARS, checkpoints[checkpoint], pos);
+        }
+
+        if pos >= self.pos {
+            self._byte_after(self.pos, self.byte, pos)
+        } else {
+            self.src[..self.byte]
+                .char_indices()
+                .rev()
+                .nth(self.pos - pos - 1)
+                .map(|(offset, _)| offset)
+        }
+    }
+
+    /// The byte offset of the given char index; decoding forward from a known char index and its byte offset.
+    fn _byte_after(&self, from: usize, byte: usize, pos: usize) -> Option<usize> {
+        match self.src[byte..].char_indices().nth(pos - from) {
+            Some((offset, _)) => Some(byte + offset),
+            None => {
+                if self.src[byte..].chars().count() == pos - from {
+                    Some(self.src.len())
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn _update_indents(&mut self, curr: char) {
+        match curr {
             '\n' => {
                 if self.indents.prev() != self.indents.curr {
                     log::vv!(
@@ -211,15 +669,39 @@ impl Cursor {
                 log::info!(&["CURSOR", "INDENT", ":START"], " curr: 0");
                 self.indents.curr = 0;
                 self.indents.is_reading = true;
+                self.indents.mixed = None;
             }
             '\t' | ' ' => {
                 if self.indents.is_reading {
-                    self.indents.curr += 1;
+                    let indentation = self.ctx.indentation;
+                    // blank lines can't decide the style.
+                    if indentation.style == IndentStyle::Detect
+                        && self.indents.detected.is_none()
+                        && self._is_indenting_content()
+                    {
+                        log::info!(
+                            &["CURSOR", "INDENT", "DETECT"],
+                            if curr == '\t' { " tabs" } else { " spaces" }
+                        );
+                        self.indents.detected = Some(curr);
+                    }
+
+                    if self.indents.mixed.is_none()
+                        && !indentation.allows(curr, self.indents.detected)
+                    {
+                        log::info!(
+                            &["CURSOR", "INDENT", "MIXED"],
+                            &format!(" @ {}", self.pos)
+                        );
+                        self.indents.mixed = Some(self.pos);
+                    }
+
+                    self.indents.curr += indentation.width_of(curr);
                     log::info!(
                         &["CURSOR", "INDENT", "APPEND"],
                         &format!(
                             " curr: {} => {} VS prev: {} ({})",
-                            self.indents.curr - 1,
+                            self.indents.curr - indentation.width_of(curr),
                             self.indents.curr,
                             self.indents.prev(),
                             if self.indents.curr > self.indents.prev() {
@@ -256,6 +738,14 @@ impl Cursor {
         }
     }
 
+    /// If the rest of the current line's indentation is followed by content; instead of being blank.
+    fn _is_indenting_content(&self) -> bool {
+        self.src[self.byte..]
+            .chars()
+            .find(|&c| c != ' ' && c != '\t')
+            .is_some_and(|c| c != '\n' && c != '\r')
+    }
+
     pub fn read_chars(&mut self, n: usize) -> Vec<char> {
         let mut result = Vec::new();
         for _ in 0..n {
@@ -297,7 +787,7 @@ impl Cursor {
             &["CURSOR", "SKIP-WHILE", "START"],
             &format!("{}..", self.pos)
         );
-        while f(self.curr()) {
+        while !self.is_eof() && f(self.curr()) {
             self.skip();
         }
         log::vvv!(&["CURSOR", "SKIP-WHILE", "END"], &format!("..{}", self.pos));
@@ -308,7 +798,7 @@ impl Cursor {
             &["CURSOR", "SKIP-UNTIL", "START"],
             &format!("{}..", self.pos)
         );
-        while !f(self.curr()) {
+        while !self.is_eof() && !f(self.curr()) {
             self.skip();
         }
         log::vvv!(&["CURSOR", "SKIP-UNTIL", "END"], &format!("..{}", self.pos));
@@ -320,7 +810,7 @@ impl Cursor {
             &format!("{}..", self.pos)
         );
         let mut result = Vec::new();
-        while f(self.curr()) {
+        while !self.is_eof() && f(self.curr()) {
             result.push(self.read());
         }
         log::vvv!(&["CURSOR", "READ-WHILE", "END"], &format!("..{}", self.pos));
@@ -333,7 +823,7 @@ impl Cursor {
             &format!("{}..", self.pos)
         );
         let mut result = Vec::new();
-        while !f(self.curr()) {
+        while !self.is_eof() && !f(self.curr()) {
             result.push(self.read());
         }
         log::vvv!(&["CURSOR", "READ-UNTIL", "END"], &format!("..{}", self.pos));
@@ -354,8 +844,14 @@ impl Cursor {
 
     /// Returns the current character being examined.
     /// This is the character that will be read next.
+    #[inline]
     pub fn curr(&self) -> char {
-        return self.at(self.pos);
+        self.examine(self.pos, self.pos);
+        return match self.src.as_bytes().get(self.byte) {
+            Some(&byte) if byte.is_ascii() => byte as char,
+            Some(_) => self.src[self.byte..].chars().next().unwrap_or('\0'),
+            None => '\0',
+        };
     }
 
     /// Returns the next character after the current character.
@@ -365,7 +861,7 @@ impl Cursor {
     }
 
     pub fn curr_str(&self) -> String {
-        return self.at(self.pos).to_string();
+        return self.curr().to_string();
     }
 
     pub fn next_str(&self) -> String {
@@ -415,12 +911,11 @@ impl Cursor {
         self.prev_is(' ') || self.prev_is('\t') || self.prev_is('\n')
     }
 
+    /// The position of the last char before the current one that isn't whitespace. (0 if there isn't one)
     pub fn prev_non_ws_pos(&self) -> usize {
-        let mut pos = self.pos - 1;
-        let mut at = self.at(pos);
-        while at.is_whitespace() {
+        let mut pos = self.prev_pos();
+        while pos > 0 && self.at(pos).is_whitespace() {
             pos -= 1;
-            at = self.at(pos);
         }
         return pos;
     }
@@ -430,19 +925,33 @@ impl Cursor {
     }
 
     pub fn back(&self, offset: usize) -> char {
-        return self.at(self.pos - offset);
+        return match self.pos.checked_sub(offset) {
+            Some(pos) => self.at(pos),
+            None => '\0',
+        };
     }
 
+    /// Returns the char at the given index. \0 if the index is past the end of the input.
     pub fn at(&self, pos: usize) -> char {
-        return self.src[pos];
+        self.examine(pos, pos);
+        return match self.byte_of(pos) {
+            Some(byte) => self.src[byte..].chars().next().unwrap_or('\0'),
+            None => '\0',
+        };
     }
 
+    #[inline]
     pub fn is_eof(&self) -> bool {
-        return self.eof_at(self.pos);
+        self.examine(self.pos, self.pos);
+        return self.byte >= self.src.len();
     }
 
     pub fn eof_at(&self, pos: usize) -> bool {
-        return pos >= self.src.len() - 1;
+        self.examine(pos, pos);
+        return match self.byte_of(pos) {
+            Some(byte) => byte >= self.src.len(),
+            None => true,
+        };
     }
 
     pub fn char_to_string(c: char) -> String {
@@ -456,7 +965,12 @@ impl Cursor {
     }
 
     pub fn slice(&self, start: usize, end: usize) -> String {
-        return self.src[start..end].iter().collect();
+        if end > start {
+            self.examine(start, end - 1);
+        }
+        let end = self.byte_of(end).unwrap_or(self.src.len());
+        let start = self.byte_of(start).unwrap_or(self.src.len()).min(end);
+        return self.src[start..end].to_string();
     }
 
     pub fn lang(&self) -> &Language {
@@ -470,3 +984,58 @@ impl Cursor {
         };
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::results::builder::Builder;
+
+    #[test]
+    fn far_positions_map_to_the_right_bytes() {
+        let source = "wörd ".repeat(100);
+        let mut cursor = Cursor::New(&source);
+        cursor.save();
+        while !cursor.is_eof() {
+            cursor.skip();
+        }
+
+        assert_eq!(cursor.at(1), 'ö');
+        assert_eq!(cursor.slice(0, 4), "wörd");
+        assert!(cursor.eof_at(500));
+        assert_eq!(cursor.at(501), '\0');
+
+        cursor.restore();
+        assert_eq!(cursor.curr(), 'w');
+        assert_eq!(cursor.slice(495, 499), "wörd");
+        assert_eq!(cursor.at(496), 'ö');
+        assert!(cursor.eof_at(500));
+        assert_eq!(cursor.at(600), '\0');
+        assert!(cursor.source_map.get().is_none());
+    }
+
+    #[test]
+    fn recovering_from_an_error_at_the_start() {
+        let mut cursor = Cursor::New("\nb");
+        cursor.enable_recovery();
+        let error = Error::New("test").build_from(0, 0).unwrap();
+
+        let token = cursor.recover(error, 0);
+        assert_eq!((token.start, token.end), (0, 0));
+        assert_eq!(cursor.recovered().len(), 1);
+        assert_eq!(cursor.prev(), '\0');
+    }
+
+    #[test]
+    fn recovering_from_whitespace_only_input() {
+        use crate::parser::{tokens::expression::literal::structure::tree, Parser as _};
+
+        let mut cursor = Cursor::New("  \n\t\n");
+        cursor.skip_ws();
+        let (result, errors) = tree::Parser.parse_with_recovery_at(&mut cursor);
+        match result {
+            Parsed::Pass(token) => assert_eq!((token.start, token.end), (5, 5)),
+            other => panic!("Expected a tree; got {:?}", other),
+        }
+        assert_eq!(errors.len(), 1);
+    }
+}
@@ -0,0 +1,311 @@
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use super::{cursor::Cursor, grammar::Grammar, results::end::End, Parser};
+
+/// How complete a rule's implementation is.
+#[derive(PartialEq, Eq, Debug, Clone, Serialize)]
+#[serde(tag = "status", content = "message", rename_all = "snake_case")]
+pub enum Status {
+    Implemented,
+    /// The rule returns `End::Not_Implemented`.
+    NotImplemented,
+    /// The rule returns `End::ToDo`; with its message.
+    ToDo(String),
+}
+
+/// A grammar rule, as described by its parser.
+#[derive(PartialEq, Eq, Debug, Clone, Serialize)]
+pub struct Rule {
+    pub name: String,
+    pub tags: Vec<String>,
+    /// The rules this one is a choice between; if it's a splay.
+    pub variants: Vec<String>,
+    /// The rules this one's own rule uses; if it isn't a splay.
+    pub subs: Vec<String>,
+    pub status: Status,
+}
+
+impl Rule {
+    #[allow(non_snake_case)]
+    pub fn Of(parser: &dyn Parser) -> Rule {
+        let variants: Vec<String> = parser
+            .variants()
+            .iter()
+            .map(|p| p.name().to_string())
+            .collect();
+
+        let mut subs: Vec<String> = Vec::new();
+        for sub in parser.subs() {
+            let name = sub.name().to_string();
+            if !variants.contains(&name) && !subs.contains(&name) {
+                subs.push(name);
+            }
+        }
+
+        Rule {
+            name: parser.name().to_string(),
+            tags: parser.tags().iter().map(|t| t.to_string()).collect(),
+            subs,
+            variants,
+            status: probe(parser),
+        }
+    }
+
+    pub fn is_splay(&self) -> bool {
+        !self.variants.is_empty()
+    }
+}
+
+/// Runs the parser's rule on an empty source to see if it bails out as unfinished.
+/// - Rules that only return `End::ToDo` for some inputs are not detected.
+fn probe(parser: &dyn Parser) -> Status {
+    // unfinished rules may also panic; which is just as unfinished, but shouldn't print.
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let end = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        parser.rule(&mut Cursor::New(""))
+    }));
+    std::panic::set_hook(hook);
+
+    match end {
+        Ok(End::Fail(error)) if error.tags.as_ref().is_some_and(|t| t.contains("TODO")) => {
+            match error.text {
+                Some(text) => Status::ToDo(strip_ansi(&text).trim_start_matches("TODO: ").to_string()),
+                None => Status::NotImplemented,
+            }
+        }
+        _ => Status::Implemented,
+    }
+}
+
+fn strip_ansi(text: &str) -> String {
+    let mut result = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|c| c.is_ascii_alphabetic());
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+impl Grammar {
+    /// Every rule in the grammar; sorted by name.
+    pub fn rules(&self) -> Vec<Rule> {
+        let mut rules: Vec<Rule> = self.parsers().map(Rule::Of).collect();
+        rules.sort_by(|a, b| a.name.cmp(&b.name));
+        rules
+    }
+
+    /// Exports the grammar as EBNF-like text.
+    /// - Splays are written as choices, and other rules as special sequences listing the rules they use.
+    pub fn to_ebnf(&self) -> String {
+        let mut result = String::new();
+        for rule in self.rules() {
+            if !rule.tags.is_empty() {
+                result.push_str(&format!(
+                    "(* {} *)\n",
+                    rule.tags.iter().map(|t| format!("#{}", t)).collect::<Vec<_>>().join(" ")
+                ));
+            }
+
+            match &rule.status {
+                Status::Implemented => {}
+                Status::NotImplemented => result.push_str("(* NOT IMPLEMENTED *)\n"),
+                Status::ToDo(message) => result.push_str(&format!("(* TODO: {} *)\n", message)),
+            }
+
+            let body = if rule.is_splay() {
+                rule.variants.join("\n  | ")
+            } else if rule.subs.is_empty() {
+                "? custom rule ?".to_string()
+            } else {
+                format!("? custom rule using: {} ?", rule.subs.join(", "))
+            };
+
+            result.push_str(&format!("{} =\n    {} ;\n\n", rule.name, body));
+        }
+
+        result
+    }
+
+    /// Exports the grammar as a Graphviz DOT digraph.
+    /// - Solid edges point to a splay's variants, and dashed edges to the rules another rule uses.
+    pub fn to_dot(&self) -> String {
+        let mut result = String::from("digraph grammar {\n  rankdir=LR;\n  node [shape=box];\n");
+        for rule in self.rules() {
+            let mut label = rule.name.clone();
+            if !rule.tags.is_empty() {
+                label.push_str(&format!(
+                    "\\n{}",
+                    rule.tags.iter().map(|t| format!("#{}", t)).collect::<Vec<_>>().join(" ")
+                ));
+            }
+
+            let style = match &rule.status {
+                Status::Implemented => "",
+                Status::NotImplemented => ", style=dashed, color=red, xlabel=\"not implemented\"",
+                Status::ToDo(_) => ", style=dashed, color=orange, xlabel=\"todo\"",
+            };
+
+            let shape = if rule.is_splay() { ", shape=diamond" } else { "" };
+            result.push_str(&format!("  \"{}\" [label=\"{}\"{}{}];\n", rule.name, label, shape, style));
+            for variant in &rule.variants {
+                result.push_str(&format!("  \"{}\" -> \"{}\";\n", rule.name, variant));
+            }
+            for sub in &rule.subs {
+                result.push_str(&format!("  \"{}\" -> \"{}\" [style=dashed];\n", rule.name, sub));
+            }
+        }
+
+        result.push_str("}\n");
+        result
+    }
+
+    /// Exports the grammar as JSON for rendering railroad diagrams.
+    /// - Each rule's `diagram` uses the node types of the railroad-diagrams library (`Choice`, `NonTerminal`, etc).
+    pub fn to_railroad_json(&self) -> Value {
+        let rules: Vec<Value> = self
+            .rules()
+            .into_iter()
+            .map(|rule| {
+                let diagram = if rule.is_splay() {
+                    json!({
+                        "type": "Choice",
+                        "index": 0,
+                        "items": rule.variants.iter().map(|v| json!({"type": "NonTerminal", "text": v})).collect::<Vec<_>>(),
+                    })
+                } else if rule.subs.is_empty() {
+                    json!({"type": "Terminal", "text": rule.name})
+                } else {
+                    json!({
+                        "type": "Group",
+                        "label": "custom rule using",
+                        "item": {
+                            "type": "Stack",
+                            "items": rule.subs.iter().map(|s| json!({"type": "NonTerminal", "text": s})).collect::<Vec<_>>(),
+                        },
+                    })
+                };
+
+                json!({
+                    "name": rule.name,
+                    "tags": rule.tags,
+                    "status": rule.status,
+                    "diagram": diagram,
+                })
+            })
+            .collect();
+
+        json!({ "rules": rules })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A small grammar; with a choice between two rules, a rule using another, and an unfinished rule.
+    mod rules {
+        pub mod digit {
+            crate::parser::tokens::token! {
+                #primitive
+                digit => |_cursor: &mut Cursor| End::None
+            }
+        }
+
+        pub mod letter {
+            crate::parser::tokens::token! {
+                letter => |_cursor: &mut Cursor| End::ToDo("read letters")
+            }
+        }
+
+        pub mod pair {
+            use super::digit;
+
+            crate::parser::tokens::token! {
+                pair => |_cursor: &mut Cursor| End::None,
+                subs: [digit]
+            }
+        }
+
+        pub mod char {
+            use super::{digit, letter};
+
+            crate::parser::tokens::splay! {
+                char: [digit, letter]
+            }
+        }
+    }
+
+    fn _grammar() -> Grammar {
+        Grammar::New()
+            .parser(rules::char::Parser.get())
+            .parser(rules::pair::Parser.get())
+    }
+
+    #[test]
+    fn describes_each_rule() {
+        let rules = _grammar().rules();
+        let names: Vec<&str> = rules.iter().map(|rule| rule.name.as_str()).collect();
+        assert_eq!(names, vec!["char", "digit", "letter", "pair"]);
+
+        assert_eq!(rules[0].variants, vec!["digit", "letter"]);
+        assert!(rules[0].subs.is_empty());
+        assert_eq!(rules[1].tags, vec!["primitive"]);
+        assert_eq!(rules[2].status, Status::ToDo("read letters".to_string()));
+        assert_eq!(rules[3].subs, vec!["digit"]);
+        assert_eq!(rules[3].status, Status::Implemented);
+    }
+
+    #[test]
+    fn exports_ebnf() {
+        assert_eq!(
+            _grammar().to_ebnf(),
+            "char =\n    digit\n  | letter ;\n\n\
+             (* #primitive *)\ndigit =\n    ? custom rule ? ;\n\n\
+             (* TODO: read letters *)\nletter =\n    ? custom rule ? ;\n\n\
+             pair =\n    ? custom rule using: digit ? ;\n\n"
+        );
+    }
+
+    #[test]
+    fn exports_dot() {
+        let dot = _grammar().to_dot();
+        assert!(dot.starts_with("digraph grammar {\n"));
+        assert!(dot.contains("  \"char\" [label=\"char\", shape=diamond];\n"));
+        assert!(dot.contains("  \"char\" -> \"digit\";\n"));
+        assert!(dot.contains("  \"digit\" [label=\"digit\\n#primitive\"];\n"));
+        assert!(dot.contains("  \"letter\" [label=\"letter\", style=dashed, color=orange, xlabel=\"todo\"];\n"));
+        assert!(dot.contains("  \"pair\" -> \"digit\" [style=dashed];\n"));
+        assert!(dot.ends_with("}\n"));
+    }
+
+    #[test]
+    fn exports_railroad_json() {
+        let json = _grammar().to_railroad_json();
+        let rules = json["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), 4);
+        assert_eq!(
+            rules[0]["diagram"],
+            json!({
+                "type": "Choice",
+                "index": 0,
+                "items": [
+                    {"type": "NonTerminal", "text": "digit"},
+                    {"type": "NonTerminal", "text": "letter"},
+                ],
+            })
+        );
+        assert_eq!(rules[1]["diagram"], json!({"type": "Terminal", "text": "digit"}));
+        assert_eq!(
+            rules[2]["status"],
+            json!({"status": "to_do", "message": "read letters"})
+        );
+        assert_eq!(rules[3]["diagram"]["item"]["items"][0]["text"], "digit");
+    }
+}
@@ -0,0 +1,181 @@
+use serde::Serialize;
+
+use super::{cursor::Cursor, source_map::SourceMap};
+
+/// Something a parser expected to find; but didn't.
+#[derive(PartialEq, Eq, Debug, Clone, Serialize)]
+pub enum Expectation {
+    /// Specific text; like a symbol or delimiter.
+    Literal(String),
+    /// A token; by the name of its parser.
+    Token(&'static str),
+    /// The closer of a delimited expression; with the index of its opener.
+    Closer { opener: char, closer: char, at: usize },
+}
+
+impl Expectation {
+    /// Like the `Display` text; but with the line and column of an opener in the given source.
+    pub fn describe(&self, map: &SourceMap) -> String {
+        match self {
+            Expectation::Closer { opener, closer, at } => {
+                let (line, column) = map.line_col_at(*at);
+                format!("`{}` (to close the `{}` at {}:{})", closer, opener, line, column)
+            }
+            expectation => expectation.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for Expectation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expectation::Literal(text) => write!(f, "`{}`", text),
+            Expectation::Token(name) => write!(f, "{}", name),
+            Expectation::Closer { opener, closer, .. } => {
+                write!(f, "`{}` (to close the `{}`)", closer, opener)
+            }
+        }
+    }
+}
+
+/// The farthest position any failed parser attempt reached; and what was expected there.
+/// - Positions are kept as char indices; so they can be shifted by edits, and are only turned into lines and columns when described.
+#[derive(PartialEq, Eq, Debug, Clone, Serialize)]
+pub struct Farthest {
+    pub pos: usize,
+    pub expected: Vec<Expectation>,
+}
+
+impl Farthest {
+    #[allow(non_snake_case)]
+    pub fn New(pos: usize, expected: Expectation) -> Farthest {
+        Farthest {
+            pos,
+            expected: vec![expected],
+        }
+    }
+
+    /// Combines the expectations of two attempts; keeping only those that got the farthest.
+    pub fn merge(self, other: Farthest) -> Farthest {
+        if other.pos > self.pos {
+            other
+        } else if other.pos < self.pos {
+            self
+        } else {
+            let mut merged = self;
+            for expectation in other.expected {
+                if !merged.expected.contains(&expectation) {
+                    merged.expected.push(expectation);
+                }
+            }
+
+            merged
+        }
+    }
+
+    /// A concise message for the given source; like: "Expected one of `:`, `>>`, `=` at 2:5, found `%`."
+    pub fn describe(&self, source: &str, map: &SourceMap) -> String {
+        let expected = self
+            .expected
+            .iter()
+            .map(|expectation| expectation.describe(map))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let found = match source[map.byte_at(self.pos)..].chars().next() {
+            Some(found) => format!("`{}`", Cursor::char_to_string(found)),
+            None => "the end of the input".to_string(),
+        };
+
+        let (line, column) = map.line_col_at(self.pos);
+        format!(
+            "Expected {}{} at {}:{}, found {}.",
+            if self.expected.len() > 1 { "one of " } else { "" },
+            expected,
+            line,
+            column,
+            found
+        )
+    }
+}
+
+impl std::fmt::Display for Farthest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Expected {}{}.",
+            if self.expected.len() > 1 { "one of " } else { "" },
+            self.expected
+                .iter()
+                .map(|expectation| expectation.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_keeps_the_farthest() {
+        let near = Farthest::New(1, Expectation::Token("word"));
+        let far = Farthest::New(3, Expectation::Literal(":".to_string()));
+        assert_eq!(near.clone().merge(far.clone()), far);
+        assert_eq!(far.clone().merge(near), far);
+    }
+
+    #[test]
+    fn merge_combines_expectations_at_the_same_position() {
+        let merged = Farthest::New(2, Expectation::Literal(":".to_string()))
+            .merge(Farthest::New(2, Expectation::Token("assigner")))
+            .merge(Farthest::New(2, Expectation::Literal(":".to_string())));
+
+        assert_eq!(
+            merged.expected,
+            vec![
+                Expectation::Literal(":".to_string()),
+                Expectation::Token("assigner")
+            ]
+        );
+    }
+
+    #[test]
+    fn describes_what_was_found() {
+        let source = "é %";
+        let map = SourceMap::New(source);
+        let farthest = Farthest::New(2, Expectation::Literal(":".to_string()))
+            .merge(Farthest::New(2, Expectation::Token("assigner")));
+        assert_eq!(
+            farthest.describe(source, &map),
+            "Expected one of `:`, assigner at 1:3, found `%`."
+        );
+
+        let farthest = Farthest::New(3, Expectation::Token("word"));
+        assert_eq!(
+            farthest.describe(source, &map),
+            "Expected word at 1:4, found the end of the input."
+        );
+    }
+
+    #[test]
+    fn describes_closers_with_the_line_and_column_of_their_opener() {
+        let source = "a\n (b";
+        let map = SourceMap::New(source);
+        let farthest = Farthest::New(
+            5,
+            Expectation::Closer {
+                opener: '(',
+                closer: ')',
+                at: 3,
+            },
+        );
+
+        assert_eq!(
+            farthest.describe(source, &map),
+            "Expected `)` (to close the `(` at 2:2) at 2:4, found the end of the input."
+        );
+        assert_eq!(farthest.to_string(), "Expected `)` (to close the `(`).");
+    }
+}
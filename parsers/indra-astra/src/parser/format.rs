@@ -0,0 +1,351 @@
+use std::collections::HashSet;
+
+use super::{
+    context::Context,
+    cst::{Cst, CstNode, Element, LeafKind},
+    cursor::Cursor,
+    results::{error::Error, node::Node, parsed::Parsed, token::Token},
+    tokens::{
+        attribute::{alias, group, input, tag, trailing},
+        source,
+        symbol::operator::assigner::{field, func, proc, var},
+    },
+    Parser,
+};
+
+/// The canonical style the formatter writes.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Style {
+    /// The text written for each level of indentation.
+    pub indent: String,
+    /// The most blank lines allowed in a row.
+    pub max_blank_lines: usize,
+}
+
+impl Style {
+    #[allow(non_snake_case)]
+    pub fn Default() -> Style {
+        Style {
+            indent: "\t".to_string(),
+            max_blank_lines: 1,
+        }
+    }
+}
+
+/// Why a source couldn't be formatted.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum FormatError {
+    /// The source doesn't parse.
+    Parse(Option<Error>),
+    /// Formatting the source would have changed its parse tree; so it was left as is.
+    Changed,
+}
+
+impl std::fmt::Display for FormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormatError::Parse(Some(error)) => write!(f, "Failed to parse: {}", error.get_message()),
+            FormatError::Parse(None) => write!(f, "Failed to parse."),
+            FormatError::Changed => write!(f, "Formatting would change the parse tree."),
+        }
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+/// Formats a source file in the default canonical style.
+pub fn format(source: &str, ctx: Context) -> Result<String, FormatError> {
+    format_with(source, ctx, &Style::Default())
+}
+
+/// Formats a source file in the given canonical style:
+/// - Lines are indented by their nesting level, and trailing whitespace is removed.
+/// - `:` follows its key directly, other assigners have a space before them, and all assigners have one space after them.
+/// - Attributes on one line are ordered as `#tags`, then `|aliases`, then `>inputs`; each separated by a space.
+/// - The formatted source is re-parsed; and is only returned if its parse tree is the same as the original's.
+pub fn format_with(source: &str, ctx: Context, style: &Style) -> Result<String, FormatError> {
+    let token = _parse(source, ctx.clone())?;
+    let cst = Cst::Of(&token, source);
+    let formatted = Formatter::New(&cst, &ctx, style).write();
+
+    match _parse(&formatted, ctx) {
+        Ok(reparsed) if Shape::Of(&token, source) == Shape::Of(&reparsed, &formatted) => Ok(formatted),
+        _ => Err(FormatError::Changed),
+    }
+}
+
+fn _parse(source: &str, ctx: Context) -> Result<Token, FormatError> {
+    let mut cursor = Cursor::New_With(source, ctx);
+    match source::Parser.parse_at(&mut cursor) {
+        Parsed::Pass(token) => Ok(token),
+        Parsed::Fail(error) => Err(FormatError::Parse(error)),
+    }
+}
+
+/// The order attributes are written in within a line.
+fn _attribute_rank(name: &str) -> usize {
+    match name {
+        tag::KEY => 0,
+        alias::KEY => 1,
+        input::KEY => 2,
+        _ => 3,
+    }
+}
+
+fn _is_attribute_list(name: &str) -> bool {
+    name == group::KEY || name == trailing::KEY
+}
+
+struct Formatter<'f> {
+    cst: &'f Cst,
+    ctx: &'f Context,
+    style: &'f Style,
+    /// The leaves in the order they're written in.
+    order: Vec<usize>,
+    /// The leaves that start an attribute that was moved; which are always preceded by a single space.
+    moved: HashSet<usize>,
+    /// The leaves that take the place of the first leaf of a reordered attribute list; and so its spacing.
+    replaced: Vec<(usize, usize)>,
+}
+
+impl<'f> Formatter<'f> {
+    #[allow(non_snake_case)]
+    fn New(cst: &'f Cst, ctx: &'f Context, style: &'f Style) -> Formatter<'f> {
+        let mut formatter = Formatter {
+            cst,
+            ctx,
+            style,
+            order: Vec::new(),
+            moved: HashSet::new(),
+            replaced: Vec::new(),
+        };
+
+        formatter.order_node(&cst.root);
+        formatter
+    }
+
+    /// Adds the leaves of the node to the order; reordering the attributes of single line attribute lists.
+    fn order_node(&mut self, node: &CstNode) {
+        if _is_attribute_list(&node.name) && !self.spans_lines(node) {
+            let mut attributes: Vec<(usize, Vec<usize>)> = node
+                .children
+                .iter()
+                .map(|child| match child {
+                    Element::Node(child) => (_attribute_rank(&child.name), _leaves_of(child)),
+                    Element::Leaf(index) => (_attribute_rank(""), vec![*index]),
+                })
+                .collect();
+
+            let first = attributes.first().map(|(_, leaves)| leaves[0]);
+            attributes.sort_by_key(|(rank, _)| *rank);
+            if let Some(first) = first
+                && first != attributes[0].1[0]
+            {
+                self.replaced.push((attributes[0].1[0], first));
+            }
+
+            for (i, (_, leaves)) in attributes.into_iter().enumerate() {
+                if i > 0 {
+                    self.moved.insert(leaves[0]);
+                }
+                self.order.extend(leaves);
+            }
+        } else {
+            for child in &node.children {
+                match child {
+                    Element::Node(child) => self.order_node(child),
+                    Element::Leaf(index) => self.order.push(*index),
+                }
+            }
+        }
+    }
+
+    /// If there's a line break between any of the node's leaves.
+    fn spans_lines(&self, node: &CstNode) -> bool {
+        let leaves = _leaves_of(node);
+        leaves.iter().enumerate().any(|(i, index)| {
+            let leaf = &self.cst.leaves[*index];
+            (i > 0 && leaf.leading.iter().any(|t| t.text.contains('\n')))
+                || (i < leaves.len() - 1 && leaf.trailing.iter().any(|t| t.text.contains('\n')))
+        })
+    }
+
+    /// The whitespace between the given leaf and the one before it in the source.
+    fn spacing_before(&self, index: usize) -> String {
+        let mut spacing = String::new();
+        if index > 0 {
+            for trivia in &self.cst.leaves[index - 1].trailing {
+                spacing.push_str(&trivia.text);
+            }
+        }
+        for trivia in &self.cst.leaves[index].leading {
+            spacing.push_str(&trivia.text);
+        }
+
+        spacing
+    }
+
+    fn write(&self) -> String {
+        let mut result = String::new();
+        let mut indents: Vec<usize> = vec![0];
+        let mut prev: Option<&str> = None;
+
+        for index in &self.order {
+            let leaf = &self.cst.leaves[*index];
+            let source_index = self
+                .replaced
+                .iter()
+                .find(|(leaf, _)| leaf == index)
+                .map_or(*index, |(_, first)| *first);
+            let spacing = self.spacing_before(source_index);
+
+            if leaf.kind == LeafKind::End {
+                if !result.is_empty() {
+                    result.push('\n');
+                }
+                break;
+            }
+
+            if spacing.contains('\n') {
+                let newlines = spacing.matches('\n').count().min(self.style.max_blank_lines + 1);
+                result.push_str(&"\n".repeat(newlines));
+
+                let width: usize = spacing
+                    .rsplit('\n')
+                    .next()
+                    .unwrap_or("")
+                    .chars()
+                    .map(|c| self.ctx.indentation.width_of(c))
+                    .sum();
+                while width < *indents.last().unwrap() {
+                    indents.pop();
+                }
+                if width > *indents.last().unwrap() {
+                    indents.push(width);
+                }
+
+                result.push_str(&self.style.indent.repeat(indents.len() - 1));
+            } else if !result.is_empty() {
+                let assigner = |name: &str| [field::KEY, proc::KEY, var::KEY, func::KEY].contains(&name);
+                let spacing = if leaf.name == field::KEY || (leaf.name == proc::KEY && leaf.text.starts_with(':')) {
+                    ""
+                } else if assigner(&leaf.name) || prev.is_some_and(assigner) || self.moved.contains(index) {
+                    " "
+                } else {
+                    &spacing
+                };
+                result.push_str(spacing);
+            }
+
+            result.push_str(&leaf.text);
+            prev = Some(&leaf.name);
+        }
+
+        result
+    }
+}
+
+fn _leaves_of(node: &CstNode) -> Vec<usize> {
+    let mut leaves = Vec::new();
+    for child in &node.children {
+        match child {
+            Element::Node(child) => leaves.extend(_leaves_of(child)),
+            Element::Leaf(index) => leaves.push(*index),
+        }
+    }
+
+    leaves
+}
+
+/// The parts of a token tree formatting must preserve; ignoring positions, indents, and the order of attributes.
+#[derive(PartialEq, Eq, Debug)]
+struct Shape {
+    name: String,
+    tags: Vec<String>,
+    text: Option<String>,
+    props: Vec<(String, Shape)>,
+    children: Vec<Shape>,
+}
+
+impl Shape {
+    #[allow(non_snake_case)]
+    fn Of(token: &Token, source: &str) -> Shape {
+        let chars: Vec<char> = source.chars().collect();
+        Shape::_Of(token, &chars)
+    }
+
+    #[allow(non_snake_case)]
+    fn _Of(token: &Token, chars: &[char]) -> Shape {
+        let mut tags: Vec<String> = token.tags().iter().cloned().collect();
+        tags.sort();
+
+        let mut props: Vec<(String, Shape)> = token
+            .keys()
+            .iter()
+            .map(|(key, index)| (key.clone(), Shape::_Of(&token.children[*index], chars)))
+            .collect();
+        props.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let prop_indexes: Vec<usize> = token.keys().values().cloned().collect();
+        let mut children: Vec<Shape> = token
+            .children
+            .iter()
+            .enumerate()
+            .filter(|(index, child)| !prop_indexes.contains(index) && !child.tag("indent"))
+            .map(|(_, child)| Shape::_Of(child, chars))
+            .collect();
+        if _is_attribute_list(&token.name) {
+            children.sort_by_key(|child| _attribute_rank(&child.name));
+        }
+
+        Shape {
+            name: token.name.clone(),
+            tags,
+            text: if token.children.is_empty() {
+                chars
+                    .get(token.start..(token.end + 1).min(chars.len()))
+                    .map(|text| text.iter().collect())
+            } else {
+                None
+            },
+            props,
+            children,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCES: [&str; 4] = [
+        "a  =   1\nb:\n    c:   2\n\n\n\nd:3   \n",
+        ">in |al #t a: 1\n- x\n-   y\n",
+        "a:\n  b:\n    c = 1\n  d: 2\n",
+        "a: 1\n",
+    ];
+
+    fn _format(source: &str) -> String {
+        format(source, Context::new_empty())
+            .unwrap_or_else(|error| panic!("Expected {:?} to format; got: {}", source, error))
+    }
+
+    #[test]
+    fn formats_in_the_canonical_style() {
+        assert_eq!(_format(SOURCES[0]), "a = 1\nb:\n\tc: 2\n\nd:3\n");
+        assert_eq!(_format(SOURCES[1]), "#t |al >in a: 1\n- x\n-   y\n");
+    }
+
+    #[test]
+    fn formatting_is_idempotent() {
+        for source in SOURCES {
+            let formatted = _format(source);
+            assert_eq!(_format(&formatted), formatted, "from {:?}", source);
+        }
+    }
+
+    #[test]
+    fn formatted_sources_are_left_as_is() {
+        assert_eq!(_format(SOURCES[3]), SOURCES[3]);
+    }
+}
@@ -1,5 +1,6 @@
 use super::context::Language;
 
+#[derive(Clone)]
 pub struct File {
     pub path: String,
     pub name: String,
@@ -10,7 +11,7 @@ impl File {
     #[allow(non_snake_case)]
     pub fn New(path: &str) -> File {
         let name = path.split("/").last().unwrap_or(path);
-        let kind = get_type(name);
+        let kind = get_type_of(name);
 
         File {
             path: path.to_string(),
@@ -147,6 +148,27 @@ pub fn get_type(extension: &str) -> Type {
     }
 }
 
+/// Gets the type of a file from its name; by the longest known extension it ends with.
+/// - ex: `config.map.ord.axa` is an ordered map, and `notes.v2.mote` is a mote.
+pub fn get_type_of(name: &str) -> Type {
+    for (index, c) in name.char_indices() {
+        if c != '.' {
+            continue;
+        }
+
+        let extension = &name[index + 1..];
+        let kind = match extension.to_lowercase().as_str() {
+            "axa" => Type::AstrA,
+            _ => get_type(extension),
+        };
+        if kind != Type::Unknown {
+            return kind;
+        }
+    }
+
+    Type::Unknown
+}
+
 pub fn get_lang(file_type: &Type) -> Language {
     match file_type {
         Type::AstrA => Language::ProX,
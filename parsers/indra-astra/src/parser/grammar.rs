@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use super::{
+    get_recursive_subs,
+    tokens::{attribute, expression, source, statement, symbol, whitespace},
+    Parser,
+};
+use crate::utils::log;
+
+#[cfg(feature = "log")]
+use crate::utils::ansi::Color;
+#[cfg(feature = "v")]
+use crate::utils::ansi::Styleable;
+
+/// An owned registry of parsers; by name.
+/// - Used to select the top-level rule to parse with by name; like the CLI's `--rule`.
+/// - Parsers still call each other directly; so a grammar can't swap out the rules used inside another rule.
+pub struct Grammar {
+    parsers: HashMap<String, Box<dyn Parser>>,
+}
+
+impl Grammar {
+    /// An empty grammar.
+    #[allow(non_snake_case)]
+    pub fn New() -> Grammar {
+        Grammar {
+            parsers: HashMap::new(),
+        }
+    }
+
+    /// The grammar of every built-in parser.
+    #[allow(non_snake_case)]
+    pub fn Default() -> Grammar {
+        log::color!("GRAMMAR", Color::Cyan);
+        log::push_unique!("GRAMMAR");
+        log::push_div!("::", Color::Cyan);
+        log::info!(&["::START"], &"Building the default grammar".color(Color::Cyan));
+        let grammar = Grammar::New()
+            .parser(expression::Parser.get())
+            .parser(statement::Parser.get())
+            .parser(symbol::Parser.get())
+            .parser(whitespace::Parser.get())
+            .parser(attribute::Parser.get())
+            .parser(source::Parser.get());
+        log::info!(
+            &["::END"],
+            &"Finished building the default grammar".color(Color::Cyan)
+        );
+        log::pop!();
+        log::pop_unique!("GRAMMAR");
+
+        grammar
+    }
+
+    /// Adds the parser, along with all of its sub-parsers; recursively.
+    /// - Replaces any existing parsers with the same names.
+    pub fn parser(mut self, parser: Box<dyn Parser>) -> Grammar {
+        self.add_parser(parser);
+        self
+    }
+
+    /// Adds the parser, along with all of its sub-parsers; recursively.
+    /// - Replaces any existing parsers with the same names.
+    pub fn add_parser(&mut self, parser: Box<dyn Parser>) -> &mut Grammar {
+        let subs = get_recursive_subs(&*parser);
+        for parser in std::iter::once(parser).chain(subs) {
+            log::info!(
+                &["ADD"],
+                &format!("Added parser: {}", parser.name()).color(Color::Cyan)
+            );
+            self.parsers.insert(parser.name().to_string(), parser);
+        }
+
+        self
+    }
+
+    /// Borrow a parser by name.
+    pub fn get(&self, name: &str) -> Option<&dyn Parser> {
+        self.parsers.get(name).map(|parser| &**parser)
+    }
+
+    /// Get a copy of a parser by name.
+    pub fn get_by_name(&self, name: &str) -> Option<Box<dyn Parser>> {
+        self.get(name).map(|p| p.get())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.parsers.contains_key(name)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.parsers.keys().map(|name| name.as_str())
+    }
+
+    pub fn parsers(&self) -> impl Iterator<Item = &dyn Parser> {
+        self.parsers.values().map(|parser| &**parser)
+    }
+
+    pub fn len(&self) -> usize {
+        self.parsers.len()
+    }
+}
@@ -0,0 +1,228 @@
+use super::{
+    context::Context,
+    cursor::{Cursor, State},
+    farthest::{Expectation, Farthest},
+    indents::Indents,
+    memo::{self, Memo},
+    results::{error::Error, parsed::Parsed, token::Token},
+    tokens::source,
+    Parser,
+};
+
+/// A text edit; replacing the characters in `start..end` with `text`.
+/// - Indices are character indices into the source as it is when the edit is applied.
+/// - A range that's reversed or runs past the end of the source is clamped to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edit {
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+}
+
+impl Edit {
+    #[allow(non_snake_case)]
+    pub fn New(start: usize, end: usize, text: &str) -> Edit {
+        Edit {
+            start: start.min(end),
+            end: start.max(end),
+            text: text.to_string(),
+        }
+    }
+
+    #[allow(non_snake_case)]
+    pub fn Insert(at: usize, text: &str) -> Edit {
+        Edit::New(at, at, text)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn Delete(start: usize, end: usize) -> Edit {
+        Edit::New(start, end, "")
+    }
+
+    /// The same edit with its range clamped to a source of `len` characters.
+    pub fn clamp(&self, len: usize) -> Edit {
+        Edit {
+            start: self.start.min(len),
+            end: self.end.min(len),
+            text: self.text.clone(),
+        }
+    }
+
+    /// Applies the edit to the given source.
+    pub fn apply_to(&self, source: &str) -> String {
+        let mut chars: Vec<char> = source.chars().collect();
+        let edit = self.clamp(chars.len());
+        chars.splice(edit.start..edit.end, edit.text.chars());
+        chars.into_iter().collect()
+    }
+
+    /// Whether the edit changes any text in, or inserts text within, the inclusive range `start..=end`.
+    pub fn touches(&self, start: usize, end: usize) -> bool {
+        start < self.end && end >= self.start
+    }
+
+    /// Moves an index from before the edit to where the same character is after it.
+    pub fn shift(&self, pos: usize) -> usize {
+        if pos >= self.end {
+            pos - self.end + self.start + self.text.chars().count()
+        } else {
+            pos
+        }
+    }
+
+    pub fn shift_state(&self, state: &mut State) {
+        state.pos = self.shift(state.pos);
+        self.shift_indents(&mut state.indents);
+    }
+
+    pub fn shift_indents(&self, indents: &mut Indents) {
+        indents.mixed = indents.mixed.map(|pos| self.shift(pos));
+    }
+
+    pub fn shift_token(&self, token: &mut Token) {
+        token.start = self.shift(token.start);
+        token.end = self.shift(token.end);
+        for child in token.children.iter_mut() {
+            self.shift_token(child);
+        }
+    }
+
+    pub fn shift_error(&self, error: &mut Error) {
+        error.start = self.shift(error.start);
+        error.end = self.shift(error.end);
+        if let Some(farthest) = error.farthest.as_mut() {
+            self.shift_farthest(farthest);
+        }
+        for child in error.children.iter_mut() {
+            self.shift_parsed(child);
+        }
+    }
+
+    pub fn shift_farthest(&self, farthest: &mut Farthest) {
+        farthest.pos = self.shift(farthest.pos);
+        for expectation in farthest.expected.iter_mut() {
+            if let Expectation::Closer { at, .. } = expectation {
+                *at = self.shift(*at);
+            }
+        }
+    }
+
+    pub fn shift_parsed(&self, parsed: &mut Parsed) {
+        match parsed {
+            Parsed::Pass(token) => self.shift_token(token),
+            Parsed::Fail(Some(error)) => self.shift_error(error),
+            Parsed::Fail(None) => {}
+        }
+    }
+}
+
+/// A parsed source that can be re-parsed after edits.
+/// - Keeps the memo table from the last parse; re-using every result that didn't look at edited text.
+/// - Owns its source and memo instead of taking a previous token tree, since a token's span doesn't record how far past it its rule looked ahead;
+///     only the memo knows which results read into an edit and can't be re-used.
+pub struct Document {
+    parser: Box<dyn Parser>,
+    ctx: Context,
+    source: String,
+    result: Parsed,
+    memo: Memo,
+}
+
+impl Document {
+    /// Parses the source as an astra source file.
+    #[allow(non_snake_case)]
+    pub fn New(source: &str) -> Document {
+        Document::New_With(source, source::Parser.get(), Context::new_empty())
+    }
+
+    #[allow(non_snake_case)]
+    pub fn New_With(source: &str, parser: Box<dyn Parser>, ctx: Context) -> Document {
+        let mut document = Document {
+            parser,
+            ctx,
+            source: source.to_string(),
+            result: Parsed::Fail(None),
+            memo: Memo::New(),
+        };
+
+        document.reparse();
+        document
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn result(&self) -> &Parsed {
+        &self.result
+    }
+
+    /// The hit/miss counters of the last (re)parse.
+    pub fn stats(&self) -> memo::Stats {
+        self.memo.stats()
+    }
+
+    /// Applies the edits in order; then re-parses, re-running only the rules whose input was changed.
+    pub fn edit(&mut self, edits: &[Edit]) -> &Parsed {
+        for edit in edits {
+            let edit = edit.clamp(self.source.chars().count());
+            self.source = edit.apply_to(&self.source);
+            self.memo.apply(&edit);
+        }
+
+        self.reparse();
+        &self.result
+    }
+
+    /// Parses the current source from scratch; without re-using any previous results.
+    pub fn parse_fresh(&self) -> Parsed {
+        let mut cursor = Cursor::New_With(&self.source, self.ctx.clone());
+        self.parser.parse_at(&mut cursor)
+    }
+
+    fn reparse(&mut self) {
+        let mut cursor = Cursor::New_With(&self.source, self.ctx.clone());
+        let mut memo = std::mem::replace(&mut self.memo, Memo::New());
+        memo.reset_stats();
+        cursor.use_memo(memo);
+
+        self.result = self.parser.parse_at(&mut cursor);
+        self.memo = cursor.take_memo().unwrap_or_else(Memo::New);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{
+        indents::{IndentStyle, Indentation},
+        tokens::whitespace::indent,
+    };
+
+    fn _mixed_start(parsed: &Parsed) -> Option<usize> {
+        match parsed {
+            Parsed::Fail(Some(error)) => indent::Find_Mixed(error).map(|mixed| mixed.start),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn edit_above_mixed_indent_matches_fresh_parse() {
+        let ctx = Context::new_empty().with_indentation(Indentation::New(IndentStyle::Detect, 4));
+        let mut document = Document::New_With("a:\n\tb: 1\nc:\n\t d: 2", source::Parser.get(), ctx);
+        assert_eq!(_mixed_start(document.result()), Some(13));
+
+        let result = document.edit(&[Edit::Insert(0, "aa")]).clone();
+        assert_eq!(result, document.parse_fresh());
+        assert_eq!(_mixed_start(&result), Some(15));
+        assert!(document.stats().hits > 0);
+    }
+
+    #[test]
+    fn edit_out_of_range_is_clamped() {
+        let mut document = Document::New("a: 1");
+        let result = document.edit(&[Edit::New(9, 2, "2")]).clone();
+        assert_eq!(document.source(), "a:2");
+        assert_eq!(result, document.parse_fresh());
+    }
+}
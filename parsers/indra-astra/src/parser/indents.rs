@@ -1,8 +1,12 @@
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Indents {
     pub curr: usize,
     pub stack: Vec<usize>,
     pub is_reading: bool,
+    /// The char used to indent the first indented line; if the policy is detected.
+    pub detected: Option<char>,
+    /// The position of the first char in the current line's indentation that breaks the policy; if any.
+    pub mixed: Option<usize>,
 }
 
 #[derive(Debug, Clone)]
@@ -11,7 +15,68 @@ pub struct LineIndent {
 }
 
 impl Indents {
+    #[allow(non_snake_case)]
+    pub fn New() -> Indents {
+        Indents {
+            curr: 0,
+            stack: Vec::new(),
+            is_reading: true,
+            detected: None,
+            mixed: None,
+        }
+    }
+
     pub fn prev(&self) -> usize {
         return self.stack.last().unwrap_or(&0).clone();
     }
 }
+
+/// Which chars may be used to indent lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IndentStyle {
+    /// Tabs and spaces can be mixed freely.
+    Any,
+    SpacesOnly,
+    TabsOnly,
+    /// Whichever of tabs or spaces the first indented line uses.
+    Detect,
+}
+
+/// How the leading whitespace of each line is measured and validated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Indentation {
+    /// The number of columns a tab counts as.
+    pub tab_width: usize,
+    pub style: IndentStyle,
+}
+
+impl Indentation {
+    #[allow(non_snake_case)]
+    pub fn New(style: IndentStyle, tab_width: usize) -> Indentation {
+        Indentation { tab_width, style }
+    }
+
+    /// Tabs and spaces can be mixed freely, and a tab counts as one column; so existing files keep parsing the same unless a stricter style or wider tab is chosen.
+    #[allow(non_snake_case)]
+    pub fn Default() -> Indentation {
+        Indentation::New(IndentStyle::Any, 1)
+    }
+
+    /// The number of columns the given indentation char counts as.
+    pub fn width_of(&self, c: char) -> usize {
+        match c {
+            '\t' => self.tab_width,
+            _ => 1,
+        }
+    }
+
+    /// If the given indentation char is allowed; given the char detected so far.
+    pub fn allows(&self, c: char, detected: Option<char>) -> bool {
+        match self.style {
+            IndentStyle::Any => true,
+            IndentStyle::SpacesOnly => c == ' ',
+            IndentStyle::TabsOnly => c == '\t',
+            IndentStyle::Detect => detected.map_or(true, |d| d == c),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use super::results::{builder::Builder, error::Error};
+
+/// The name of the error returned once a cursor has run out of fuel.
+pub const FUEL_KEY: &str = "exceeded_fuel_limit";
+/// The name of the error returned once parsers have been nested too deeply.
+pub const DEPTH_KEY: &str = "exceeded_depth_limit";
+
+/// How much work parsing with a cursor may do before giving up.
+/// - Fuel is the number of parser attempts allowed; including ones that fail or are recalled from the memo.
+/// - Depth is the number of parser attempts allowed to be nested inside each other.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Limits {
+    pub fuel: Option<usize>,
+    pub depth: Option<usize>,
+}
+
+impl Limits {
+    /// No limits; which callers opt into with `fuel` and `depth`.
+    #[allow(non_snake_case)]
+    pub fn Default() -> Limits {
+        Limits::None()
+    }
+
+    #[allow(non_snake_case)]
+    pub fn None() -> Limits {
+        Limits {
+            fuel: None,
+            depth: None,
+        }
+    }
+
+    pub fn fuel(mut self, fuel: usize) -> Limits {
+        self.fuel = Some(fuel);
+        self
+    }
+
+    pub fn depth(mut self, depth: usize) -> Limits {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// The error for the limit exceeded by taking the given step at the given depth; if any.
+    pub(crate) fn check(&self, steps: usize, depth: usize, pos: usize) -> Option<Error> {
+        if let Some(fuel) = self.fuel
+            && steps > fuel
+        {
+            Some(
+                Error::New(FUEL_KEY)
+                    .text(&format!("Ran out of fuel after {} parser steps.", fuel))
+                    .tag("limit")
+                    .build_from(pos, pos)
+                    .unwrap(),
+            )
+        } else if let Some(max) = self.depth
+            && depth > max
+        {
+            Some(
+                Error::New(DEPTH_KEY)
+                    .text(&format!("Parsers were nested more than {} deep.", max))
+                    .tag("limit")
+                    .build_from(pos, pos)
+                    .unwrap(),
+            )
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+
+use super::{
+    cursor::State,
+    farthest::Farthest,
+    incremental::Edit,
+    indents::Indents,
+    results::{error::Error, parsed::Parsed},
+};
+
+/// Identifies a single parser attempt: which parser ran, where it started, and the indentation it started with.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Key {
+    pub parser: &'static str,
+    pub pos: usize,
+    pub indents: Indents,
+    /// The chars that close the delimited expression the parser started within; if any.
+    pub closers: Option<&'static [char]>,
+}
+
+/// A remembered parser result, along with the cursor state the parser's rule left behind.
+pub struct Entry {
+    pub result: Parsed,
+    pub end: State,
+    /// Errors recovered from while producing the result.
+    pub recovered: Vec<Error>,
+    /// The (inclusive) range of source characters the parser looked at to produce the result.
+    pub examined: Option<(usize, usize)>,
+    /// What the parser's failed attempts expected, at the farthest position they reached.
+    pub farthest: Option<Farthest>,
+}
+
+/// Hit/miss counters for a memo table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: usize,
+    pub misses: usize,
+    pub entries: usize,
+}
+
+/// A packrat memo table of parser results keyed by parser name, position and indent state.
+pub struct Memo {
+    entries: HashMap<Key, Entry>,
+    hits: usize,
+    misses: usize,
+}
+
+impl Memo {
+    #[allow(non_snake_case)]
+    pub fn New() -> Memo {
+        Memo {
+            entries: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Looks up a previous result; counting the attempt as a hit or a miss.
+    pub fn recall(&mut self, key: &Key) -> Option<&Entry> {
+        match self.entries.get(key) {
+            Some(entry) => {
+                self.hits += 1;
+                Some(entry)
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    pub fn remember(
+        &mut self,
+        key: Key,
+        result: Parsed,
+        end: State,
+        recovered: Vec<Error>,
+        examined: Option<(usize, usize)>,
+        farthest: Option<Farthest>,
+    ) {
+        self.entries.insert(
+            key,
+            Entry {
+                result,
+                end,
+                recovered,
+                examined,
+                farthest,
+            },
+        );
+    }
+
+    /// Drops every entry that looked at text changed by the edit, and shifts the rest to their new positions.
+    pub fn apply(&mut self, edit: &Edit) {
+        let entries = std::mem::take(&mut self.entries);
+        self.entries = entries
+            .into_iter()
+            .filter(|(key, entry)| {
+                let (start, end) = entry.examined.unwrap_or((key.pos, key.pos));
+                !edit.touches(start.min(key.pos), end.max(entry.end.pos))
+            })
+            .map(|(mut key, mut entry)| {
+                key.pos = edit.shift(key.pos);
+                edit.shift_indents(&mut key.indents);
+                edit.shift_state(&mut entry.end);
+                entry.examined = entry
+                    .examined
+                    .map(|(start, end)| (edit.shift(start), edit.shift(end)));
+                if let Some(farthest) = entry.farthest.as_mut() {
+                    edit.shift_farthest(farthest);
+                }
+                edit.shift_parsed(&mut entry.result);
+                for error in entry.recovered.iter_mut() {
+                    edit.shift_error(error);
+                }
+
+                (key, entry)
+            })
+            .collect();
+    }
+
+    /// Resets the hit/miss counters; keeping the entries.
+    pub fn reset_stats(&mut self) {
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    pub fn stats(&self) -> Stats {
+        Stats {
+            hits: self.hits,
+            misses: self.misses,
+            entries: self.entries.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::results::parsed::Parsed;
+
+    fn _key(pos: usize, mixed: Option<usize>) -> Key {
+        let mut indents = Indents::New();
+        indents.mixed = mixed;
+        Key {
+            parser: "test",
+            pos,
+            indents,
+            closers: None,
+        }
+    }
+
+    fn _state(pos: usize, mixed: Option<usize>) -> State {
+        let mut indents = Indents::New();
+        indents.mixed = mixed;
+        State {
+            pos,
+            indents,
+            recovered: 0,
+        }
+    }
+
+    #[test]
+    fn apply_shifts_mixed_indent_positions() {
+        let mut memo = Memo::New();
+        memo.remember(
+            _key(10, Some(11)),
+            Parsed::Fail(None),
+            _state(12, Some(11)),
+            Vec::new(),
+            Some((10, 12)),
+            None,
+        );
+
+        memo.apply(&Edit::Insert(0, "aa"));
+        assert!(memo.recall(&_key(10, Some(11))).is_none());
+        let entry = memo.recall(&_key(12, Some(13))).unwrap();
+        assert_eq!(entry.end.pos, 14);
+        assert_eq!(entry.end.indents.mixed, Some(13));
+    }
+
+    #[test]
+    fn recall_counts_hits_and_misses() {
+        let mut memo = Memo::New();
+        assert!(memo.recall(&_key(0, None)).is_none());
+        memo.remember(
+            _key(0, None),
+            Parsed::Fail(None),
+            _state(0, None),
+            Vec::new(),
+            None,
+            None,
+        );
+        assert!(memo.recall(&_key(0, None)).is_some());
+        assert!(memo.recall(&_key(0, None)).is_some());
+        assert!(memo.recall(&_key(1, None)).is_none());
+
+        assert_eq!(
+            memo.stats(),
+            Stats {
+                hits: 2,
+                misses: 2,
+                entries: 1
+            }
+        );
+
+        memo.reset_stats();
+        assert_eq!(
+            memo.stats(),
+            Stats {
+                hits: 0,
+                misses: 0,
+                entries: 1
+            }
+        );
+
+        memo.clear();
+        assert_eq!(memo.stats(), Stats::default());
+    }
+}
@@ -1,22 +1,34 @@
 pub mod colors;
 pub mod context;
+pub mod cst;
 pub mod cursor;
+pub mod export;
+pub mod farthest;
+pub mod format;
 pub mod fs;
+pub mod grammar;
+pub mod incremental;
 pub mod indents;
+pub mod limits;
+pub mod memo;
 pub mod results;
+pub mod source_map;
 pub mod tokens;
+pub mod trace;
 
-use self::tokens::{attribute, expression};
-use crate::{
-    parser::tokens::{source, statement, symbol, whitespace},
-    utils::log::{self},
-};
-use core::panic;
+use crate::utils::log::{self};
 use cursor::Cursor;
-use results::{builder::Builder, end::End, parsed::Parsed, token::Token};
-use std::sync::LazyLock;
-use std::{any::TypeId, sync::Mutex};
-use std::{cell::LazyCell, collections::HashMap};
+use farthest::Expectation;
+use results::{
+    builder::Builder,
+    end::End,
+    error::{Error, CHOICE_TAG},
+    error_builder::ErrorBuilder,
+    parsed::Parsed,
+    token::Token,
+};
+use std::any::TypeId;
+use trace::Event;
 
 #[cfg(feature = "log")]
 use crate::utils::ansi::Color;
@@ -27,50 +39,6 @@ use crate::utils::ansi::Styleable;
 #[cfg(feature = "log")]
 use crate::utils::sexp::SExpressable;
 
-// #region All
-/// A hashmap of all parsers.
-pub static ALL: LazyLock<HashMap<String, Box<dyn Parser>>> = LazyLock::new(|| get_each());
-
-static mut _ALL: LazyCell<Mutex<HashMap<String, Box<dyn Parser>>>> =
-    LazyCell::new(|| Mutex::new(HashMap::new()));
-
-/// Used to initialize all parsers.
-/// MUST be called before the static ALL collection above or any of the get functions below are used.
-pub fn init_all() {
-    log::color!("INIT", Color::Cyan);
-    log::push_unique!("INIT");
-    log::push_div!("::", Color::Cyan);
-    log::info!(&["::START"], &"Initializing all parsers".color(Color::Cyan));
-    add_r!(expression);
-    add_r!(statement);
-    add_r!(symbol);
-    add_r!(whitespace);
-    add_r!(attribute);
-    add_r!(source);
-    log::info!(
-        &["::END"],
-        &"Finished initializing all parsers".color(Color::Cyan)
-    );
-    log::pop!();
-    log::pop_unique!("INIT");
-}
-
-/// Borrow the hashmap of all parsers.
-pub fn get_all() -> &'static std::collections::HashMap<String, Box<dyn Parser>> {
-    &ALL
-}
-
-/// Get a copy of the hashmap of all parsers.
-pub fn get_each() -> std::collections::HashMap<String, Box<dyn Parser>> {
-    let all: &std::collections::HashMap<String, Box<dyn Parser>> = unsafe { &_ALL.lock().unwrap() };
-    let mut map = HashMap::new();
-    for (key, value) in all {
-        map.insert(key.to_string(), value.get());
-    }
-
-    map
-}
-
 /// Get all sub-parsers of the given parser; recursively.
 pub fn get_recursive_subs(parser: &dyn Parser) -> Vec<Box<dyn Parser>> {
     let mut subs = Vec::new();
@@ -81,33 +49,6 @@ pub fn get_recursive_subs(parser: &dyn Parser) -> Vec<Box<dyn Parser>> {
     subs
 }
 
-/// Gets a parser by name
-pub fn get_by_name(name: &str) -> Option<Box<dyn Parser>> {
-    get_all().get(name).map(|p| p.get())
-}
-
-macro_rules! add_r {
-    ($i:ident) => {
-        let lock = unsafe { _ALL.lock() };
-        let mut all = lock.ok().unwrap();
-        all.insert(stringify!($i).to_string(), Box::new($i::Parser));
-        log::info!(
-            &["ADD"],
-            &format!("Added parser: {}", stringify!($i)).color(Color::Cyan)
-        );
-        all.extend(get_recursive_subs(&$i::Parser::Get()).into_iter().map(|p| {
-            log::info!(
-                &["ADD"],
-                &format!("Added parser: {}", p.name()).color(Color::Cyan)
-            );
-            (p.name().to_string(), p.get())
-        }));
-        drop(all);
-    };
-}
-pub(super) use add_r;
-// #endregion
-
 pub trait Parser: Sync + Send {
     // #region Static
     // #region Get
@@ -179,6 +120,11 @@ pub trait Parser: Sync + Send {
 
     fn subs(&self) -> Vec<Box<dyn crate::parser::Parser>>;
 
+    /// The parsers this one splays into (tries in order); if it's a choice between them.
+    fn variants(&self) -> Vec<Box<dyn crate::parser::Parser>> {
+        Vec::new()
+    }
+
     fn type_id(&self) -> TypeId
     where
         Self: 'static,
@@ -278,6 +224,22 @@ pub trait Parser: Sync + Send {
         }
     }
 
+    /// Attempt to parse the input; recovering from errors within trees and entries where possible.
+    /// - Returns the (partial) result, along with every error that was recovered from.
+    fn parse_with_recovery(&self, input: &str) -> (Parsed, Vec<Error>) {
+        let mut cursor = Cursor::New(input);
+        self.parse_with_recovery_at(&mut cursor)
+    }
+
+    /// Attempt to parse using the cursor; recovering from errors within trees and entries where possible.
+    /// - Returns the (partial) result, along with every error that was recovered from.
+    fn parse_with_recovery_at(&self, cursor: &mut Cursor) -> (Parsed, Vec<Error>) {
+        cursor.enable_recovery();
+        let result = self.parse_at(cursor);
+
+        (result, cursor.take_recovered())
+    }
+
     /// Parses the input with the given options.
     /// * `input` - The input to parse.
     /// * `optional` - If true; the parser will revert to the previous state on fail.
@@ -291,6 +253,9 @@ pub trait Parser: Sync + Send {
     /// * `cursor` - The cursor to start parsing with (Always starts at the current cursor position (`.curr_pos()`)).
     /// * `optional` - If true; the parser will revert to the previous state on fail.
     /// * `ignored` - If true; prints a verbose ignored message instead of a fail message to the logs.
+    /// - If the cursor is memoized; previous results for the same parser, position and indents are reused.
+    /// - If the cursor is tracing; records entering and exiting the parser.
+    /// - If the cursor's fuel or depth limit is exceeded; fails with the limit's error instead.
     fn parse_with_options_at(&self, cursor: &mut Cursor, optional: bool, ignored: bool) -> Parsed {
         log::color!("PARSE", Color::Green);
         log::push_unique!("PARSE");
@@ -298,14 +263,78 @@ pub trait Parser: Sync + Send {
         #[cfg(feature = "vv")]
         log::push_div!(":", Color::Green);
         log::info!(&[":START"], &format!("@ {}", cursor.curr_pos()));
+        if cursor.is_tracing() {
+            cursor.record(Event::Enter {
+                parser: self.name().to_string(),
+                pos: cursor.curr_pos(),
+            });
+        }
+
+        if let Some(error) = cursor.enter() {
+            let result = Parsed::Fail(Some(error));
+            if cursor.is_tracing() {
+                cursor.record(Event::Exit_Of(self.name(), &result, cursor.curr_pos(), false));
+            }
+
+            cursor.exit();
+            log::info!(&[":END", "LIMIT"], &format!("@ {}", cursor.curr_pos()));
+            log::pop!();
+            #[cfg(feature = "vv")]
+            log::pop!();
+            log::pop_unique!("PARSE");
+
+            return result;
+        }
+
+        let memo_key = cursor.memo_key(self.name());
+        if let Some(ref key) = memo_key {
+            if let Some((result, end, recovered)) = cursor.recall(key) {
+                if let Parsed::Pass(_) = result {
+                    cursor.jump_to(end);
+                } else if !optional {
+                    cursor.jump_to(end);
+                }
+                cursor.append_recovered(recovered);
+                if cursor.is_tracing() {
+                    cursor.record(Event::Exit_Of(self.name(), &result, cursor.curr_pos(), true));
+                }
 
+                cursor.exit();
+                log::info!(&[":END", "MEMO"], &format!("@ {}", cursor.curr_pos()));
+                log::pop!();
+                #[cfg(feature = "vv")]
+                log::pop!();
+                log::pop_unique!("PARSE");
+
+                return result;
+            }
+        }
+
+        let recovered_before = cursor.recovered().len();
         let start = if optional {
             cursor.save()
         } else {
             cursor.curr_pos()
         };
 
-        let result = match self.rule(cursor) {
+        let outer_examined = cursor.start_examining();
+        let outer_expected = cursor.start_expecting();
+        let outcome = match self.rule(cursor) {
+            End::Fail(error) => End::Fail(expect(self.name(), cursor, start, error)),
+            End::None => {
+                // nothing more specific was expected.
+                if cursor.farthest().map_or(true, |farthest| farthest.pos < start) {
+                    cursor.expect(start, Expectation::Token(self.name()));
+                }
+
+                End::None
+            }
+            outcome => outcome,
+        };
+        let farthest = cursor.stop_expecting(outer_expected);
+        let examined = cursor.stop_examining(outer_examined);
+        let end_state = memo_key.as_ref().map(|_| cursor.state());
+        let result = match outcome {
             End::Match(token) => {
                 let end = if start >= cursor.curr_pos() {
                     start
@@ -331,7 +360,7 @@ pub trait Parser: Sync + Send {
                         cursor.prev_pos(),
                         if log::IS_VV { "\n" } else { "" },
                         if log::IS_VV {
-                            token.to_sexp_str(&cursor._src)
+                            token.to_sexp_str(&cursor.source())
                         } else {
                             token.name.clone()
                         }
@@ -351,17 +380,16 @@ pub trait Parser: Sync + Send {
                     .assure_name(self.name())
                     .build_with_defaults(start, end);
 
-                if optional {
+                let error = if optional {
                     cursor.restore();
                     if start != cursor.curr_pos() {
-                        panic!(
-                            "Optional parser '{}' did not revert to the previous state. {} != {}",
-                            self.name(),
-                            start,
-                            cursor.curr_pos()
-                        );
+                        Some(_unreverted(self.name(), start, cursor.curr_pos()))
+                    } else {
+                        error
                     }
-                }
+                } else {
+                    error
+                };
 
                 if ignored {
                     if log::IS_VV {
@@ -376,7 +404,7 @@ pub trait Parser: Sync + Send {
                                 "@ {} => \n{}",
                                 cursor.prev_pos(),
                                 match error {
-                                    Some(ref e) => e.to_sexp_str(&cursor._src),
+                                    Some(ref e) => e.to_sexp_str(&cursor.source()),
                                     None => "<None>".to_string(),
                                 }
                             )
@@ -403,7 +431,7 @@ pub trait Parser: Sync + Send {
                             if log::IS_VV { "\n" } else { "" },
                             if log::IS_VV {
                                 match error {
-                                    Some(ref e) => e.to_sexp_str(&cursor._src),
+                                    Some(ref e) => e.to_sexp_str(&cursor.source()),
                                     None => "<None>".to_string(),
                                 }
                             } else {
@@ -423,31 +451,48 @@ pub trait Parser: Sync + Send {
             End::None => {
                 if optional {
                     cursor.restore();
-                    if start != cursor.curr_pos() {
-                        panic!(
-                            "Optional parser '{}' did not revert to the previous state. {} != {}",
-                            self.name(),
-                            start,
-                            cursor.curr_pos()
-                        );
-                    }
                 }
 
-                #[cfg(feature = "log")]
-                let end = if start >= cursor.curr_pos() {
-                    start
+                if optional && start != cursor.curr_pos() {
+                    let error = _unreverted(self.name(), start, cursor.curr_pos());
+                    log::info!(
+                        &[":END", &"FAIL".color(Color::Red)],
+                        &format!("@ {} => {}", start, error.name).color(Color::Red),
+                    );
+                    Parsed::Fail(Some(error))
                 } else {
-                    cursor.prev_pos()
-                };
+                    #[cfg(feature = "log")]
+                    let end = if start >= cursor.curr_pos() {
+                        start
+                    } else {
+                        cursor.prev_pos()
+                    };
 
-                log::info!(
-                    &[":END", &"NONE".color(Color::BrightBlack)],
-                    &format!("@ {}", end)
-                );
-                Parsed::Fail(None)
+                    log::info!(
+                        &[":END", &"NONE".color(Color::BrightBlack)],
+                        &format!("@ {}", end)
+                    );
+                    Parsed::Fail(None)
+                }
             }
         };
 
+        // results cut short by a limit would be wrong to reuse.
+        if let (Some(key), Some(end_state), None) = (memo_key, end_state, cursor.exceeded_limit()) {
+            cursor.remember(key, &result, end_state, recovered_before, examined, farthest);
+        }
+
+        cursor.exit();
+        let result = match cursor.exceeded_limit() {
+            // the outermost parser fails with the exceeded limit; even if it could still make something of what it got.
+            Some(error) if cursor.depth() == 0 => Parsed::Fail(Some(error.clone())),
+            _ => result,
+        };
+
+        if cursor.is_tracing() {
+            cursor.record(Event::Exit_Of(self.name(), &result, cursor.curr_pos(), false));
+        }
+
         log::pop!();
         #[cfg(feature = "vv")]
         log::pop!();
@@ -470,6 +515,38 @@ pub trait Parser: Sync + Send {
 }
 // #endregion
 
+/// The name of the internal error for an optional parser that moved the cursor without reverting it.
+pub const UNREVERTED_KEY: &str = "unreverted_optional_parser";
+
+/// The internal error for an optional parser that didn't revert the cursor to where it started.
+fn _unreverted(parser: &str, start: usize, pos: usize) -> Error {
+    Error::New(UNREVERTED_KEY)
+        .text(&format!(
+            "Optional parser '{}' did not revert to the previous state. {} != {}",
+            parser, start, pos
+        ))
+        .tag("internal")
+        .build_from(start, start)
+        .unwrap()
+}
+
+/// Records what the failed parser attempt expected; and notes on errors for failed choices what was expected where the alternatives got the farthest.
+fn expect(parser: &'static str, cursor: &mut Cursor, start: usize, mut error: ErrorBuilder) -> ErrorBuilder {
+    let pos = cursor.curr_pos().max(start);
+    if let Some(expected) = error.expected.take() {
+        cursor.expect(pos, Expectation::Literal(expected));
+    } else if cursor.farthest().map_or(true, |farthest| farthest.pos < pos) {
+        cursor.expect(pos, Expectation::Token(parser));
+    }
+
+    let is_choice = error.tags.as_ref().is_some_and(|tags| tags.contains(CHOICE_TAG));
+    if is_choice && let Some(farthest) = cursor.farthest() {
+        error.set_farthest(farthest.clone());
+    }
+
+    error
+}
+
 // #region Macros
 
 macro_rules! req_child {
@@ -518,3 +595,34 @@ macro_rules! opt_child {
 }
 
 pub(crate) use opt_child;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use results::node::Node;
+
+    /// A parser that saves the cursor after reading a char; and ends without restoring or popping the save.
+    mod leaky {
+        use crate::parser::tokens::token;
+
+        token! {
+            leaky => |cursor: &mut Cursor| {
+                cursor.read();
+                cursor.save();
+                End::None
+            }
+        }
+    }
+
+    #[test]
+    fn unreverted_optional_parsers_fail_with_an_internal_error() {
+        let mut cursor = Cursor::New("ab");
+        let error = match leaky::Parser::Parse_Opt_At(&mut cursor) {
+            Parsed::Fail(Some(error)) => error,
+            other => panic!("Expected an internal error; got {:?}", other),
+        };
+
+        assert_eq!(error.name, UNREVERTED_KEY);
+        assert!(error.tags().contains("internal"));
+    }
+}
@@ -0,0 +1,559 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{
+    error::Error,
+    parsed::Parsed,
+    token::Token,
+    visit::{Path, Step},
+};
+use crate::utils::ansi::{Color, Styleable};
+
+/// A difference between two trees of tokens or errors.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Change {
+    /// A node only in the new tree; at its path in the new tree.
+    Added { path: Path, name: String },
+    /// A node only in the old tree; at its path in the old tree.
+    Removed { path: Path, name: String },
+    Renamed { path: Path, from: String, to: String },
+    Tags {
+        path: Path,
+        added: Vec<String>,
+        removed: Vec<String>,
+    },
+    Props {
+        path: Path,
+        added: Vec<String>,
+        removed: Vec<String>,
+    },
+    /// The node's span moved or changed size.
+    Span {
+        path: Path,
+        from: (usize, usize),
+        to: (usize, usize),
+    },
+    /// The message of an error changed.
+    Text {
+        path: Path,
+        from: Option<String>,
+        to: Option<String>,
+    },
+}
+
+impl Change {
+    pub fn path(&self) -> &Path {
+        match self {
+            Change::Added { path, .. }
+            | Change::Removed { path, .. }
+            | Change::Renamed { path, .. }
+            | Change::Tags { path, .. }
+            | Change::Props { path, .. }
+            | Change::Span { path, .. }
+            | Change::Text { path, .. } => path,
+        }
+    }
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::Added { path, name } => write!(f, "{}: added {}", path, name),
+            Change::Removed { path, name } => write!(f, "{}: removed {}", path, name),
+            Change::Renamed { path, from, to } => write!(f, "{}: renamed {} to {}", path, from, to),
+            Change::Tags { path, added, removed } => {
+                write!(f, "{}: tags {}", path, _format_set_changes("#", added, removed))
+            }
+            Change::Props { path, added, removed } => {
+                write!(f, "{}: props {}", path, _format_set_changes("", added, removed))
+            }
+            Change::Span { path, from, to } => write!(
+                f,
+                "{}: span [{}..{}] to [{}..{}]",
+                path, from.0, from.1, to.0, to.1
+            ),
+            Change::Text { path, from, to } => write!(f, "{}: text {:?} to {:?}", path, from, to),
+        }
+    }
+}
+
+/// The differences between two trees of tokens or errors; with a unified view of both trees.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Diff {
+    pub changes: Vec<Change>,
+    lines: Vec<Line>,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+struct Line {
+    mark: Mark,
+    depth: usize,
+    text: String,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+enum Mark {
+    Same,
+    Added,
+    Removed,
+    Changed,
+}
+
+impl Diff {
+    #[allow(non_snake_case)]
+    pub fn Of(old: &Parsed, new: &Parsed) -> Diff {
+        Diff::_Of(Item::Of_Parsed(old), Item::Of_Parsed(new))
+    }
+
+    #[allow(non_snake_case)]
+    pub fn Of_Tokens(old: &Token, new: &Token) -> Diff {
+        Diff::_Of(Item::Token(old), Item::Token(new))
+    }
+
+    #[allow(non_snake_case)]
+    pub fn Of_Errors(old: &Error, new: &Error) -> Diff {
+        Diff::_Of(Item::Error(old), Item::Error(new))
+    }
+
+    #[allow(non_snake_case)]
+    fn _Of(old: Item, new: Item) -> Diff {
+        let mut diff = Diff {
+            changes: Vec::new(),
+            lines: Vec::new(),
+        };
+
+        diff._compare(old, new, Path::Root(), Path::Root(), None, 0);
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Both trees in one; with removed nodes marked `-`, added ones `+`, and changed ones `~`.
+    pub fn to_unified_str(&self) -> String {
+        self._to_unified_str(true)
+    }
+
+    /// The unified view; without any color.
+    pub fn to_plain_str(&self) -> String {
+        self._to_unified_str(false)
+    }
+
+    fn _to_unified_str(&self, colored: bool) -> String {
+        let mut result = String::new();
+        for line in &self.lines {
+            let (mark, color) = match line.mark {
+                Mark::Same => (' ', Color::BrightBlack),
+                Mark::Added => ('+', Color::Green),
+                Mark::Removed => ('-', Color::Red),
+                Mark::Changed => ('~', Color::Yellow),
+            };
+
+            let text = format!("{} {}{}", mark, "  ".repeat(line.depth), line.text);
+            if colored {
+                result.push_str(&text.color(color));
+            } else {
+                result.push_str(&text);
+            }
+            result.push('\n');
+        }
+
+        result
+    }
+
+    fn _compare(&mut self, old: Item, new: Item, old_path: Path, new_path: Path, key: Option<&str>, depth: usize) {
+        let mut changes = Vec::new();
+        if old.name() != new.name() {
+            changes.push(Change::Renamed {
+                path: new_path.clone(),
+                from: old.name(),
+                to: new.name(),
+            });
+        }
+
+        let (old_tags, new_tags) = (old.tags(), new.tags());
+        if old_tags != new_tags {
+            changes.push(Change::Tags {
+                path: new_path.clone(),
+                added: _sorted(new_tags.difference(&old_tags)),
+                removed: _sorted(old_tags.difference(&new_tags)),
+            });
+        }
+
+        let (old_keys, new_keys) = (old.keys(), new.keys());
+        let (old_key_set, new_key_set): (HashSet<&String>, HashSet<&String>) =
+            (old_keys.keys().collect(), new_keys.keys().collect());
+        if old_key_set != new_key_set {
+            changes.push(Change::Props {
+                path: new_path.clone(),
+                added: _sorted(new_key_set.difference(&old_key_set).cloned()),
+                removed: _sorted(old_key_set.difference(&new_key_set).cloned()),
+            });
+        }
+
+        if old.span() != new.span() {
+            changes.push(Change::Span {
+                path: new_path.clone(),
+                from: old.span(),
+                to: new.span(),
+            });
+        }
+
+        if old.text() != new.text() {
+            changes.push(Change::Text {
+                path: new_path.clone(),
+                from: old.text(),
+                to: new.text(),
+            });
+        }
+
+        let mut head = new.head(key);
+        if !changes.is_empty() {
+            let notes: Vec<String> = changes
+                .iter()
+                .map(|change| match change {
+                    Change::Renamed { from, .. } => format!("was {}", from),
+                    Change::Tags { added, removed, .. } => _format_set_changes("#", added, removed),
+                    Change::Props { added, removed, .. } => {
+                        format!("props {}", _format_set_changes("", added, removed))
+                    }
+                    Change::Span { from, .. } => format!("was [{}..{}]", from.0, from.1),
+                    Change::Text { from, .. } => format!("was {:?}", from.clone().unwrap_or_default()),
+                    _ => String::new(),
+                })
+                .collect();
+            head = format!("{}  <- {}", head, notes.join("; "));
+        }
+        self.lines.push(Line {
+            mark: if changes.is_empty() { Mark::Same } else { Mark::Changed },
+            depth,
+            text: head,
+        });
+        self.changes.extend(changes);
+
+        // props are matched by key, and other children by their names; in order.
+        let (old_children, new_children) = (old.children(), new.children());
+        let old_rest: Vec<usize> = (0..old_children.len()).filter(|i| old.key(*i).is_none()).collect();
+        let new_rest: Vec<usize> = (0..new_children.len()).filter(|i| new.key(*i).is_none()).collect();
+        let mut pairs: Vec<(Option<usize>, Option<usize>)> = Vec::new();
+        let mut aligned = _align(
+            &old_rest.iter().map(|i| old_children[*i].name()).collect::<Vec<_>>(),
+            &new_rest.iter().map(|i| new_children[*i].name()).collect::<Vec<_>>(),
+        )
+        .into_iter()
+        .map(|(o, n)| (o.map(|o| old_rest[o]), n.map(|n| new_rest[n])))
+        .peekable();
+
+        // props are listed where they are in the new tree; or in the old tree if they were removed.
+        let mut removed_props: Vec<usize> = (0..old_children.len())
+            .filter(|i| old.key(*i).is_some_and(|k| !new_keys.contains_key(k)))
+            .collect();
+        for index in 0..new_children.len() {
+            match new.key(index) {
+                Some(key) => pairs.push((old_keys.get(key).cloned(), Some(index))),
+                None => {
+                    while let Some((o, n)) = aligned.next() {
+                        pairs.push((o, n));
+                        if n.is_some() {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        pairs.extend(aligned);
+        pairs.extend(removed_props.drain(..).map(|index| (Some(index), None)));
+
+        for (o, n) in pairs {
+            match (o, n) {
+                (Some(o), Some(n)) => self._compare(
+                    old_children[o].clone(),
+                    new_children[n].clone(),
+                    old_path.child_at(old.key(o), o),
+                    new_path.child_at(new.key(n), n),
+                    new.key(n).map(|k| k.as_str()),
+                    depth + 1,
+                ),
+                (Some(o), None) => {
+                    let path = old_path.child_at(old.key(o), o);
+                    self.changes.push(Change::Removed {
+                        path,
+                        name: old_children[o].name(),
+                    });
+                    self._list(&old_children[o], old.key(o).map(|k| k.as_str()), Mark::Removed, depth + 1);
+                }
+                (None, Some(n)) => {
+                    let path = new_path.child_at(new.key(n), n);
+                    self.changes.push(Change::Added {
+                        path,
+                        name: new_children[n].name(),
+                    });
+                    self._list(&new_children[n], new.key(n).map(|k| k.as_str()), Mark::Added, depth + 1);
+                }
+                (None, None) => {}
+            }
+        }
+    }
+
+    /// Lists a whole subtree that was added or removed.
+    fn _list(&mut self, item: &Item, key: Option<&str>, mark: Mark, depth: usize) {
+        self.lines.push(Line {
+            mark,
+            depth,
+            text: item.head(key),
+        });
+
+        for (index, child) in item.children().iter().enumerate() {
+            self._list(child, item.key(index).map(|k| k.as_str()), mark, depth + 1);
+        }
+    }
+}
+
+impl Path {
+    fn child_at(&self, key: Option<&String>, index: usize) -> Path {
+        let mut steps = self.steps.clone();
+        steps.push(match key {
+            Some(key) => Step::Prop(key.clone()),
+            None => Step::Index(index),
+        });
+
+        Path { steps }
+    }
+}
+
+/// A token, an error, or a missing error; all compared the same way.
+#[derive(Clone)]
+enum Item<'t> {
+    Token(&'t Token),
+    Error(&'t Error),
+    None,
+}
+
+impl<'t> Item<'t> {
+    #[allow(non_snake_case)]
+    fn Of_Parsed(parsed: &'t Parsed) -> Item<'t> {
+        match parsed {
+            Parsed::Pass(token) => Item::Token(token),
+            Parsed::Fail(Some(error)) => Item::Error(error),
+            Parsed::Fail(None) => Item::None,
+        }
+    }
+
+    fn name(&self) -> String {
+        match self {
+            Item::Token(token) => token.name.clone(),
+            Item::Error(error) => format!("err::{}", error.name),
+            Item::None => "none".to_string(),
+        }
+    }
+
+    fn tags(&self) -> HashSet<String> {
+        match self {
+            Item::Token(token) => token.tags.clone().unwrap_or_default(),
+            Item::Error(error) => error.tags.clone().unwrap_or_default(),
+            Item::None => HashSet::new(),
+        }
+    }
+
+    fn keys(&self) -> HashMap<String, usize> {
+        match self {
+            Item::Token(token) => token.keys.clone().unwrap_or_default(),
+            Item::Error(error) => error.keys.clone().unwrap_or_default(),
+            Item::None => HashMap::new(),
+        }
+    }
+
+    fn key(&self, index: usize) -> Option<&'t String> {
+        let keys = match self {
+            Item::Token(token) => token.keys.as_ref(),
+            Item::Error(error) => error.keys.as_ref(),
+            Item::None => None,
+        };
+
+        keys.and_then(|keys| keys.iter().find(|(_, i)| **i == index).map(|(key, _)| key))
+    }
+
+    fn span(&self) -> (usize, usize) {
+        match self {
+            Item::Token(token) => (token.start, token.end),
+            Item::Error(error) => (error.start, error.end),
+            Item::None => (0, 0),
+        }
+    }
+
+    fn text(&self) -> Option<String> {
+        match self {
+            Item::Error(error) => error.text.clone(),
+            _ => None,
+        }
+    }
+
+    fn children(&self) -> Vec<Item<'t>> {
+        match self {
+            Item::Token(token) => token.children.iter().map(Item::Token).collect(),
+            Item::Error(error) => error.children.iter().map(Item::Of_Parsed).collect(),
+            Item::None => Vec::new(),
+        }
+    }
+
+    /// A one line summary of the node; like `key: (name [0..2] #key)`.
+    fn head(&self, key: Option<&str>) -> String {
+        let (start, end) = self.span();
+        let mut tags: Vec<String> = self.tags().into_iter().collect();
+        tags.sort();
+        format!(
+            "{}({}{}{})",
+            key.map_or(String::new(), |key| format!("{}: ", key)),
+            self.name(),
+            match self {
+                Item::None => String::new(),
+                _ => format!(" [{}..{}]", start, end),
+            },
+            tags.iter().map(|tag| format!(" #{}", tag)).collect::<String>()
+        )
+    }
+}
+
+/// Pairs up the items of two lists with their longest common subsequence of names; leaving the rest unpaired.
+fn _align(old: &[String], new: &[String]) -> Vec<(Option<usize>, Option<usize>)> {
+    let mut lengths = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for o in (0..old.len()).rev() {
+        for n in (0..new.len()).rev() {
+            lengths[o][n] = if old[o] == new[n] {
+                lengths[o + 1][n + 1] + 1
+            } else {
+                lengths[o + 1][n].max(lengths[o][n + 1])
+            };
+        }
+    }
+
+    let mut pairs = Vec::new();
+    let (mut o, mut n) = (0, 0);
+    while o < old.len() || n < new.len() {
+        if o < old.len() && n < new.len() && old[o] == new[n] {
+            pairs.push((Some(o), Some(n)));
+            o += 1;
+            n += 1;
+        } else if o < old.len() && (n == new.len() || lengths[o + 1][n] >= lengths[o][n + 1]) {
+            pairs.push((Some(o), None));
+            o += 1;
+        } else {
+            pairs.push((None, Some(n)));
+            n += 1;
+        }
+    }
+
+    // an item removed right before one is added in its place is a change of the same item.
+    let mut merged: Vec<(Option<usize>, Option<usize>)> = Vec::new();
+    for pair in pairs {
+        if let Some(last) = merged.last_mut()
+            && last.0.is_some()
+            && last.1.is_none()
+            && pair.0.is_none()
+        {
+            last.1 = pair.1;
+        } else {
+            merged.push(pair);
+        }
+    }
+
+    merged
+}
+
+fn _sorted<'s>(items: impl Iterator<Item = &'s String>) -> Vec<String> {
+    let mut items: Vec<String> = items.cloned().collect();
+    items.sort();
+    items
+}
+
+fn _format_set_changes(prefix: &str, added: &[String], removed: &[String]) -> String {
+    added
+        .iter()
+        .map(|item| format!("+{}{}", prefix, item))
+        .chain(removed.iter().map(|item| format!("-{}{}", prefix, item)))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::results::builder::Builder;
+
+    fn _leaf(name: &str, start: usize, end: usize) -> Token {
+        Token::New().name(name).build_from(start, end)
+    }
+
+    #[test]
+    fn same_trees_have_no_changes() {
+        let token = Token::New()
+            .name("tree")
+            .child(_leaf("branch", 0, 2))
+            .build_from(0, 2);
+
+        let diff = Diff::Of_Tokens(&token, &token.clone());
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_plain_str(), "  (tree [0..2])\n    (branch [0..2])\n");
+    }
+
+    #[test]
+    fn lists_changes_with_their_paths() {
+        let old = Token::New()
+            .name("var")
+            .tag("const")
+            .prop("key", _leaf("name", 0, 0))
+            .prop("value", _leaf("integer", 4, 4))
+            .child(_leaf("comment", 6, 8))
+            .build_from(0, 8);
+        let new = Token::New()
+            .name("var")
+            .tag("mutable")
+            .prop("key", _leaf("name", 0, 0))
+            .prop("operator", _leaf("assigner", 2, 3))
+            .prop("value", _leaf("decimal", 5, 7))
+            .build_from(0, 7);
+
+        let changes: Vec<String> = Diff::Of_Tokens(&old, &new)
+            .changes
+            .iter()
+            .map(|change| change.to_string())
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                "/: tags +#mutable -#const",
+                "/: props +operator",
+                "/: span [0..8] to [0..7]",
+                "/operator: added assigner",
+                "/value: renamed integer to decimal",
+                "/value: span [4..4] to [5..7]",
+                "/2: removed comment",
+            ]
+        );
+    }
+
+    #[test]
+    fn shows_both_trees_in_one() {
+        let old = Token::New()
+            .name("tree")
+            .child(_leaf("branch", 0, 2))
+            .child(_leaf("branch", 4, 6))
+            .build_from(0, 6);
+        let new = Token::New()
+            .name("tree")
+            .child(_leaf("branch", 0, 2))
+            .child(_leaf("error", 4, 6))
+            .build_from(0, 6);
+
+        assert_eq!(
+            Diff::Of_Tokens(&old, &new).to_plain_str(),
+            "  (tree [0..6])\n    (branch [0..2])\n~   (error [4..6])  <- was branch\n"
+        );
+
+        let longer = old.to_builder().child(_leaf("branch", 8, 9)).build_from(0, 9);
+        assert_eq!(
+            Diff::Of_Tokens(&new, &longer).to_plain_str(),
+            "~ (tree [0..9])  <- was [0..6]\n    (branch [0..2])\n~   (branch [4..6])  <- was error\n+   (branch [8..9])\n"
+        );
+    }
+}
@@ -6,7 +6,7 @@ use crate::{
 };
 
 use super::{
-    builder::Builder, error::Error, error_builder::ErrorBuilder, parsed::Parsed, token::Token,
+    builder::Builder, error::{self, Error}, error_builder::ErrorBuilder, parsed::Parsed, token::Token,
     token_builder::TokenBuilder,
 };
 
@@ -245,7 +245,8 @@ impl End {
                 .map(|option| format!("\t- {}", option))
                 .collect::<Vec<String>>()
                 .join("\n")
-        ));
+        ))
+        .add_tag(error::CHOICE_TAG);
         for failure in failures {
             error.add_child(Parsed::Fail(failure));
         }
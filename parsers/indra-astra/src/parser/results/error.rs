@@ -10,11 +10,21 @@ use crate::{
 };
 use std::collections::{HashMap, HashSet};
 
-use crate::parser::results::node::{Node, _EMPTY_KEYS, _EMPTY_TAGS};
+use crate::parser::{
+    farthest::Farthest,
+    results::node::{Node, _EMPTY_KEYS, _EMPTY_TAGS},
+};
 
 use super::{builder::Builder, span::Span};
 use serde::{Deserialize, Serialize};
 
+/// The name of the token inserted into the token tree in place of a recovered error.
+pub const TOKEN_KEY: &str = "error";
+
+/// The tag for errors from failing to match any of several alternatives.
+/// - They note what was expected where the alternatives got the farthest; when known.
+pub const CHOICE_TAG: &str = "choice";
+
 pub struct ChildOrError {
     pub child: Option<Token>,
     pub err: Option<Error>,
@@ -29,6 +39,9 @@ pub struct Error {
     pub end: usize,
     pub children: Vec<Parsed>,
     pub keys: Option<HashMap<String, usize>>,
+    /// What was expected where the attempts behind the error got the farthest; if noted.
+    #[serde(default, skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub farthest: Option<Farthest>,
 }
 
 impl Error {
@@ -70,6 +83,7 @@ impl Error {
             "Expected: `{}`, but found: `{}`.",
             expected, found
         ))
+        .set_expected(expected)
         .add_tag("unexpected")
         .add_tag("missing");
         End::Fail(err)
@@ -82,6 +96,7 @@ impl Error {
             "Expected: `{}`, but found: `{}`.",
             expected, found
         ))
+        .set_expected(expected)
         .add_tag("missing")
         .add_tag("unexpected");
         End::Fail(err)
@@ -104,6 +119,8 @@ impl Error {
                     .collect(),
             ),
             keys: Some(parent.keys.unwrap_or(HashMap::new())),
+            expected: None,
+            farthest: None,
         };
 
         parent_err.add_tag("incomplete");
@@ -129,6 +146,8 @@ impl Error {
                     .collect(),
             ),
             keys: Some(parent.keys.unwrap_or(HashMap::new())),
+            expected: None,
+            farthest: None,
         };
 
         parent_err.add_tag("incomplete");
@@ -152,7 +171,8 @@ impl Error {
                 .map(|option| format!("\t- {}", option))
                 .collect::<Vec<String>>()
                 .join("\n")
-        ));
+        ))
+        .add_tag(CHOICE_TAG);
         for failure in failures {
             error.add_child(Parsed::Fail(failure));
         }
@@ -184,7 +204,8 @@ impl Error {
                 ))
                 .collect::<Vec<String>>()
                 .join("\n")
-        ));
+        ))
+        .add_tag(CHOICE_TAG);
         for failure in failures {
             parent_error.add_child(Parsed::Fail(failure));
         }
@@ -205,9 +226,18 @@ impl Error {
                 None
             },
             keys: self.keys,
+            expected: None,
+            farthest: self.farthest,
         };
     }
 
+    /// Builds an error token that stands in for this error within a (partial) token tree.
+    pub fn to_token(&self, end: usize) -> Token {
+        Token::With_Name(TOKEN_KEY)
+            .tag(&self.name)
+            .build_from(self.start, end.max(self.start))
+    }
+
     pub fn get_message(&self) -> String {
         let current = &self;
         let mut result = self.text.clone().unwrap_or("".to_string());
@@ -284,8 +314,9 @@ impl SExpressable<Parsed> for Error {
         Color::BrightRed
     }
     fn extra_subs(&self, config: &mut SFormat) -> Vec<String> {
+        let mut subs = Vec::new();
         if !self.get_message().is_empty() {
-            vec![format!(
+            subs.push(format!(
                 "{}: {}",
                 "ERROR".bg(Color::Red).color(Color::White),
                 if config.colors.is_some() {
@@ -296,10 +327,22 @@ impl SExpressable<Parsed> for Error {
                 } else {
                     format!("{}: {}", "ERROR", self.get_message().indent(1))
                 }
-            )]
-        } else {
-            vec![]
+            ));
         }
+
+        if let Some(farthest) = &self.farthest {
+            subs.push(format!(
+                "{}: {}",
+                "FARTHEST".bg(Color::Yellow).color(Color::Black),
+                if config.colors.is_some() {
+                    format!("@ {} => {}", farthest.pos, farthest).color(Color::Yellow)
+                } else {
+                    format!("@ {} => {}", farthest.pos, farthest)
+                }
+            ));
+        }
+
+        subs
     }
     fn node_to_sexp_str(node: &Parsed, config: &mut SFormat) -> String {
         match node {
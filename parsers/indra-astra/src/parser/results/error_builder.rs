@@ -1,5 +1,8 @@
 use crate::{
-    parser::results::{builder::Builder, end::End, error::Error, parsed::Parsed},
+    parser::{
+        farthest::{Expectation, Farthest},
+        results::{builder::Builder, end::End, error::Error, parsed::Parsed},
+    },
     utils::log,
 };
 use std::collections::{HashMap, HashSet};
@@ -12,6 +15,11 @@ pub struct ErrorBuilder {
     pub tags: Option<HashSet<String>>,
     pub children: Option<Vec<Parsed>>,
     pub keys: Option<HashMap<String, usize>>,
+    /// The text that was expected instead; if the error is for something specific that's missing.
+    /// - Parsers failing with the error note it as what they expected; otherwise it becomes the error's farthest expectation when built.
+    pub expected: Option<String>,
+    /// What was expected where the attempts behind the error got the farthest; if noted.
+    pub farthest: Option<Farthest>,
 }
 
 impl ErrorBuilder {
@@ -25,6 +33,8 @@ impl ErrorBuilder {
             tags: None,
             children: None,
             keys: None,
+            expected: None,
+            farthest: None,
         }
     }
 
@@ -52,6 +62,35 @@ impl ErrorBuilder {
         self
     }
 
+    pub fn expected(mut self, expected: &str) -> ErrorBuilder {
+        self.expected = Some(expected.to_string());
+        self
+    }
+
+    pub fn set_expected(&mut self, expected: &str) -> &mut ErrorBuilder {
+        self.expected = Some(expected.to_string());
+        self
+    }
+
+    pub fn farthest(mut self, farthest: Farthest) -> ErrorBuilder {
+        self.farthest = Some(farthest);
+        self
+    }
+
+    pub fn set_farthest(&mut self, farthest: Farthest) -> &mut ErrorBuilder {
+        self.farthest = Some(farthest);
+        self
+    }
+
+    /// The farthest expectation to build the error with; falling back on the expected text at the given start.
+    fn _farthest(&mut self, start: usize) -> Option<Farthest> {
+        self.farthest.take().or_else(|| {
+            self.expected
+                .take()
+                .map(|expected| Farthest::New(start, Expectation::Literal(expected)))
+        })
+    }
+
     pub fn text(mut self, text: &str) -> ErrorBuilder {
         log::vvv!(
             &["ERROR", "-", "TEXT"],
@@ -264,7 +303,7 @@ impl Builder<Option<Error>> for ErrorBuilder {
         }
     }
 
-    fn build_from(self, start: usize, end: usize) -> Option<Error> {
+    fn build_from(mut self, start: usize, end: usize) -> Option<Error> {
         if end < start {
             panic!("error builder called with end < start: {} < {}", end, start);
         }
@@ -274,12 +313,14 @@ impl Builder<Option<Error>> for ErrorBuilder {
             &format!("{} : ({}, {})", self.name, start, end)
         );
 
+        let farthest = self._farthest(start);
         return Some(Error {
             name: self.name,
             text: self.text,
             tags: self.tags,
             children: self.children.unwrap_or(Vec::new()),
             keys: self.keys,
+            farthest,
             start,
             end,
         });
@@ -299,7 +340,7 @@ impl Builder<Option<Error>> for ErrorBuilder {
         self.build_from(start, end)
     }
 
-    fn build_with_defaults(self, start: usize, end: usize) -> Option<Error> {
+    fn build_with_defaults(mut self, start: usize, end: usize) -> Option<Error> {
         let start = self.start.unwrap_or(start);
         let end = self.end.unwrap_or(end);
         if end < start {
@@ -311,12 +352,14 @@ impl Builder<Option<Error>> for ErrorBuilder {
             &format!("{} : ({}, {})", self.name, start, end)
         );
 
+        let farthest = self._farthest(start);
         return Some(Error {
             name: self.name,
             text: self.text,
             tags: self.tags,
             children: self.children.unwrap_or(Vec::new()),
             keys: self.keys,
+            farthest,
             start,
             end,
         });
@@ -336,3 +379,33 @@ impl Builder<Option<Error>> for ErrorBuilder {
         return End::Fail(self);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expected_text_becomes_the_farthest_expectation() {
+        let error = Error::New("missing_colon")
+            .expected(":")
+            .build_from(2, 3)
+            .unwrap();
+
+        assert_eq!(
+            error.farthest,
+            Some(Farthest::New(2, Expectation::Literal(":".to_string())))
+        );
+    }
+
+    #[test]
+    fn noted_farthest_expectations_are_kept() {
+        let farthest = Farthest::New(5, Expectation::Token("word"));
+        let error = Error::New("missing_colon")
+            .expected(":")
+            .farthest(farthest.clone())
+            .build_with_defaults(2, 3)
+            .unwrap();
+
+        assert_eq!(error.farthest, Some(farthest));
+    }
+}
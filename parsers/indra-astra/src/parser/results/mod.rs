@@ -1,9 +1,13 @@
 pub mod builder;
+pub mod diff;
 pub mod end;
 pub mod error;
 pub mod error_builder;
 pub mod node;
 pub mod parsed;
+pub mod select;
 pub mod span;
 pub mod token;
 pub mod token_builder;
+pub mod view;
+pub mod visit;
@@ -0,0 +1,358 @@
+use super::{
+    node::Node,
+    token::Token,
+    visit::{Path, Step},
+};
+
+/// A query for tokens in a tree; like a css selector.
+/// - `name` matches tokens with the name, or that are the prop with that key.
+/// - `*` matches any token.
+/// - `#tag` matches tokens with the tag (or name).
+/// - `.key` matches tokens that are the prop with that key.
+/// - `:nth(n)` matches the n-th (from 0) of its siblings that match the rest of the selector.
+/// - `a b` matches `b` tokens anywhere within `a` tokens, and `a > b` only the children of `a` tokens.
+/// - Examples: `named_entry > .key`, `*#expression`, `tree branch:nth(2)`.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Selector {
+    compounds: Vec<Compound>,
+    /// The combinator before each compound after the first.
+    combinators: Vec<Combinator>,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Combinator {
+    Descendant,
+    Child,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+struct Compound {
+    name: Option<String>,
+    tags: Vec<String>,
+    keys: Vec<String>,
+    nth: Option<usize>,
+}
+
+/// Why a selector couldn't be parsed.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SelectorError {
+    /// The char index in the selector where the error was found.
+    pub pos: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for SelectorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid selector at {}: {}", self.pos, self.message)
+    }
+}
+
+impl std::error::Error for SelectorError {}
+
+/// A token matched by a selector; and the path to it from the root of the search.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Match<'t> {
+    pub token: &'t Token,
+    pub path: Path,
+}
+
+impl Selector {
+    #[allow(non_snake_case)]
+    pub fn Parse(selector: &str) -> Result<Selector, SelectorError> {
+        let chars: Vec<char> = selector.chars().collect();
+        let mut pos = 0;
+        let mut compounds = Vec::new();
+        let mut combinators = Vec::new();
+
+        loop {
+            let start = pos;
+            while pos < chars.len() && chars[pos].is_whitespace() {
+                pos += 1;
+            }
+
+            if pos >= chars.len() {
+                break;
+            }
+
+            if !compounds.is_empty() {
+                if chars[pos] == '>' {
+                    pos += 1;
+                    while pos < chars.len() && chars[pos].is_whitespace() {
+                        pos += 1;
+                    }
+                    if pos >= chars.len() {
+                        return Err(_error(pos, "Expected a token to select after `>`."));
+                    }
+                    combinators.push(Combinator::Child);
+                } else if pos > start {
+                    combinators.push(Combinator::Descendant);
+                } else {
+                    return Err(_error(pos, &format!("Unexpected `{}`.", chars[pos])));
+                }
+            }
+
+            compounds.push(_parse_compound(&chars, &mut pos)?);
+        }
+
+        if compounds.is_empty() {
+            return Err(_error(0, "Expected at least one token to select."));
+        }
+
+        Ok(Selector {
+            compounds,
+            combinators,
+        })
+    }
+
+    /// Every token in the tree the selector matches; in order, with their paths from the root.
+    pub fn select<'t>(&self, root: &'t Token) -> Vec<Match<'t>> {
+        let mut matches = Vec::new();
+        let mut chain = Vec::new();
+        self._select(root, Path::Root(), &mut chain, &mut matches);
+
+        matches
+    }
+
+    fn _select<'t>(&self, token: &'t Token, path: Path, chain: &mut Vec<&'t Token>, matches: &mut Vec<Match<'t>>) {
+        chain.push(token);
+        if self._matches_at(self.compounds.len() - 1, chain, &path, chain.len() - 1) {
+            matches.push(Match {
+                token,
+                path: path.clone(),
+            });
+        }
+
+        for (index, child) in token.children.iter().enumerate() {
+            self._select(child, path.child(token, index), chain, matches);
+        }
+        chain.pop();
+    }
+
+    /// If the compound at the given index matches the token at the given index in the chain of ancestors; and the compounds before it match its ancestors.
+    fn _matches_at(&self, compound: usize, chain: &[&Token], path: &Path, at: usize) -> bool {
+        if !self.compounds[compound].matches(chain, path, at) {
+            return false;
+        }
+
+        if compound == 0 {
+            return true;
+        }
+
+        match self.combinators[compound - 1] {
+            Combinator::Child => at > 0 && self._matches_at(compound - 1, chain, path, at - 1),
+            Combinator::Descendant => (0..at).rev().any(|ancestor| self._matches_at(compound - 1, chain, path, ancestor)),
+        }
+    }
+}
+
+impl Compound {
+    fn matches(&self, chain: &[&Token], path: &Path, at: usize) -> bool {
+        let key = match at {
+            0 => None,
+            _ => match &path.steps[at - 1] {
+                Step::Prop(key) => Some(key.as_str()),
+                Step::Index(_) => None,
+            },
+        };
+
+        if !self._matches(chain[at], key) {
+            return false;
+        }
+
+        match self.nth {
+            None => true,
+            Some(n) => match at {
+                0 => n == 0,
+                _ => {
+                    let parent = chain[at - 1];
+                    parent
+                        .children
+                        .iter()
+                        .enumerate()
+                        .filter(|(index, sibling)| self._matches(sibling, parent.key(*index).map(|k| k.as_str())))
+                        .nth(n)
+                        .is_some_and(|(_, sibling)| std::ptr::eq(sibling, chain[at]))
+                }
+            },
+        }
+    }
+
+    /// If the token matches everything but the position.
+    fn _matches(&self, token: &Token, key: Option<&str>) -> bool {
+        self.name.as_ref().map_or(true, |name| token.name == *name || key == Some(name))
+            && self.tags.iter().all(|tag| token.tag(tag))
+            && self.keys.iter().all(|k| key == Some(k))
+    }
+}
+
+impl Token {
+    /// Every token in this one's tree the selector matches; see `Selector`.
+    pub fn select(&self, selector: &str) -> Result<Vec<Match<'_>>, SelectorError> {
+        Ok(Selector::Parse(selector)?.select(self))
+    }
+}
+
+fn _parse_compound(chars: &[char], pos: &mut usize) -> Result<Compound, SelectorError> {
+    let start = *pos;
+    let mut compound = Compound::default();
+    if chars[*pos] == '*' {
+        *pos += 1;
+    } else if _is_ident_char(chars[*pos]) {
+        compound.name = Some(_parse_ident(chars, pos));
+    }
+
+    while *pos < chars.len() {
+        match chars[*pos] {
+            '#' => {
+                *pos += 1;
+                compound.tags.push(_parse_required_ident(chars, pos, "tag")?);
+            }
+            '.' => {
+                *pos += 1;
+                compound.keys.push(_parse_required_ident(chars, pos, "prop key")?);
+            }
+            ':' => {
+                *pos += 1;
+                let pseudo = _parse_required_ident(chars, pos, "pseudo selector")?;
+                if pseudo != "nth" {
+                    return Err(_error(*pos - pseudo.chars().count(), &format!("Unknown pseudo selector `:{}`.", pseudo)));
+                }
+
+                if chars.get(*pos) != Some(&'(') {
+                    return Err(_error(*pos, "Expected `(` after `:nth`."));
+                }
+                *pos += 1;
+
+                let digits_start = *pos;
+                while *pos < chars.len() && chars[*pos].is_ascii_digit() {
+                    *pos += 1;
+                }
+                let digits: String = chars[digits_start..*pos].iter().collect();
+                compound.nth = Some(digits.parse().map_err(|_| _error(digits_start, "Expected an index."))?);
+
+                if chars.get(*pos) != Some(&')') {
+                    return Err(_error(*pos, "Expected `)` to close `:nth(`."));
+                }
+                *pos += 1;
+            }
+            c if c.is_whitespace() || c == '>' => break,
+            c => return Err(_error(*pos, &format!("Unexpected `{}`.", c))),
+        }
+    }
+
+    if *pos == start {
+        return Err(_error(start, &format!("Unexpected `{}`.", chars[start])));
+    }
+
+    Ok(compound)
+}
+
+fn _parse_required_ident(chars: &[char], pos: &mut usize, what: &str) -> Result<String, SelectorError> {
+    if *pos < chars.len() && _is_ident_char(chars[*pos]) {
+        Ok(_parse_ident(chars, pos))
+    } else {
+        Err(_error(*pos, &format!("Expected a {}.", what)))
+    }
+}
+
+fn _parse_ident(chars: &[char], pos: &mut usize) -> String {
+    let start = *pos;
+    while *pos < chars.len() && _is_ident_char(chars[*pos]) {
+        *pos += 1;
+    }
+
+    chars[start..*pos].iter().collect()
+}
+
+fn _is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-'
+}
+
+fn _error(pos: usize, message: &str) -> SelectorError {
+    SelectorError {
+        pos,
+        message: message.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::results::builder::Builder;
+
+    fn _leaf(name: &str, tag: &str, at: usize) -> Token {
+        Token::New().name(name).tag(tag).build_from(at, at)
+    }
+
+    fn _entry(at: usize) -> Token {
+        Token::New()
+            .name("named_entry")
+            .tag("entry")
+            .prop("key", _leaf("name", "key", at))
+            .prop("value", _leaf("integer", "number", at + 3))
+            .build_from(at, at + 3)
+    }
+
+    /// A tree of three branches; the last holding a nested tree with one more entry.
+    fn _tree() -> Token {
+        Token::New()
+            .name("tree")
+            .child(Token::New().name("branch").child(_entry(0)).build_from(0, 3))
+            .child(Token::New().name("branch").child(_entry(5)).build_from(5, 8))
+            .child(
+                Token::New()
+                    .name("branch")
+                    .child(Token::New().name("tree").child(_entry(11)).build_from(11, 14))
+                    .build_from(10, 14),
+            )
+            .build_from(0, 14)
+    }
+
+    fn _starts(root: &Token, selector: &str) -> Vec<usize> {
+        root.select(selector)
+            .unwrap()
+            .iter()
+            .map(|found| found.token.start)
+            .collect()
+    }
+
+    #[test]
+    fn matches_names_tags_and_keys() {
+        let tree = _tree();
+        assert_eq!(_starts(&tree, "named_entry"), vec![0, 5, 11]);
+        assert_eq!(_starts(&tree, "*#number"), vec![3, 8, 14]);
+        assert_eq!(_starts(&tree, ".key"), vec![0, 5, 11]);
+        assert_eq!(_starts(&tree, "key"), vec![0, 5, 11]);
+        assert_eq!(_starts(&tree, "integer.key"), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn matches_children_and_descendants() {
+        let tree = _tree();
+        assert_eq!(_starts(&tree, "tree named_entry"), vec![0, 5, 11]);
+        assert_eq!(_starts(&tree, "tree > branch > named_entry"), vec![0, 5]);
+        assert_eq!(_starts(&tree, "branch tree > named_entry > .value"), vec![14]);
+    }
+
+    #[test]
+    fn matches_the_nth_sibling() {
+        let tree = _tree();
+        assert_eq!(_starts(&tree, "branch:nth(1)"), vec![5]);
+        assert_eq!(_starts(&tree, "tree:nth(0)"), vec![0, 11]);
+    }
+
+    #[test]
+    fn paths_lead_to_the_matches() {
+        let tree = _tree();
+        let found = tree.select("branch:nth(2) .value").unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].path.to_string(), "/2/0/0/value");
+    }
+
+    #[test]
+    fn invalid_selectors_report_where() {
+        for (selector, pos) in [("", 0), ("a >", 3), ("a:first", 2), ("a:nth(x)", 6), ("a:nth(1", 7), ("a$", 1)] {
+            assert_eq!(Selector::Parse(selector).unwrap_err().pos, pos, "for {:?}", selector);
+        }
+    }
+}
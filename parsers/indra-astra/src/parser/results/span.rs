@@ -1,7 +1,24 @@
+use crate::parser::source_map::SourceMap;
+
 pub trait Span {
     fn start(&self) -> usize;
     fn end(&self) -> usize;
     fn range(&self) -> std::ops::Range<usize> {
         return self.start()..self.end();
     }
+
+    /// The (line, column) of the first and last chars of the span.
+    fn line_col_range(&self, map: &SourceMap) -> std::ops::RangeInclusive<(usize, usize)> {
+        return map.line_col_at(self.start())..=map.line_col_at(self.end());
+    }
+
+    /// The UTF-8 byte range of the span; for slicing the source `&str`.
+    fn byte_range(&self, map: &SourceMap) -> std::ops::Range<usize> {
+        return map.byte_at(self.start())..map.byte_at(self.end() + 1);
+    }
+
+    /// The UTF-16 code unit range of the span.
+    fn utf16_range(&self, map: &SourceMap) -> std::ops::Range<usize> {
+        return map.utf16_at(self.start())..map.utf16_at(self.end() + 1);
+    }
 }
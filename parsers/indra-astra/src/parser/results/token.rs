@@ -54,27 +54,34 @@ impl Token {
     #[allow(non_snake_case)]
     pub fn Choice(source: &str, cursor: &mut Cursor, options: &[&dyn parser::Parser]) -> Parsed {
         let mut errors = Vec::new();
+        let outer_expected = cursor.start_expecting();
         for option in options {
             match option.parse_opt_at(cursor) {
-                Parsed::Pass(token) => return Parsed::Pass(token),
+                Parsed::Pass(token) => {
+                    cursor.stop_expecting(outer_expected);
+                    return Parsed::Pass(token);
+                }
                 Parsed::Fail(err) => match err {
                     Some(err) => errors.push(err),
                     None => (),
                 },
             }
         }
+        let farthest = cursor.stop_expecting(outer_expected);
 
         if errors.is_empty() {
             return Parsed::Fail(None);
         } else {
-            return Parsed::Fail(
-                Error::Missing_Choice(
-                    source,
-                    options.iter().map(|option| option.name()).collect(),
-                    errors.into_iter().map(|err| Some(err)).collect(),
-                )
-                .build_to(cursor.curr_pos()),
+            let mut error = Error::Missing_Choice(
+                source,
+                options.iter().map(|option| option.name()).collect(),
+                errors.into_iter().map(|err| Some(err)).collect(),
             );
+            if let Some(farthest) = farthest {
+                error.set_farthest(farthest);
+            }
+
+            return Parsed::Fail(error.build_to(cursor.curr_pos()));
         }
     }
 
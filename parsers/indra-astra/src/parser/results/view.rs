@@ -0,0 +1,189 @@
+use std::marker::PhantomData;
+
+use super::{node::Node, token::Token};
+use crate::parser::Parser;
+
+/// Why a token couldn't be viewed as a specific type of token.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum ViewError {
+    /// The token was made by another parser.
+    Mismatch {
+        expected: &'static str,
+        found: String,
+    },
+    /// The token is missing a prop its type always has.
+    Missing {
+        token: &'static str,
+        prop: &'static str,
+    },
+    /// The token's text can't be read as the value its type has; like a number that doesn't fit.
+    Invalid {
+        token: &'static str,
+        message: String,
+    },
+}
+
+impl std::fmt::Display for ViewError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ViewError::Mismatch { expected, found } => {
+                write!(f, "Expected a {} token, but found a {} token.", expected, found)
+            }
+            ViewError::Missing { token, prop } => {
+                write!(f, "Expected the {} token to have a {} prop.", token, prop)
+            }
+            ViewError::Invalid { token, message } => {
+                write!(f, "Expected a valid {} token: {}", token, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ViewError {}
+
+/// A checked view of a token made by the parser `P`; or by a splay that had `P` as one of its variants.
+#[derive(Debug)]
+pub struct View<'t, P: Parser> {
+    pub token: &'t Token,
+    parser: PhantomData<P>,
+}
+
+impl<'t, P: Parser> Clone for View<'t, P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'t, P: Parser> Copy for View<'t, P> {}
+
+impl<'t, P: Parser> View<'t, P> {
+    /// The name of the type of token this views.
+    pub fn key() -> &'static str {
+        P::Get().name()
+    }
+
+    pub fn prop(&self, key: &str) -> Option<&'t Token> {
+        self.token.prop(key)
+    }
+
+    /// The given prop; or an error if the token is missing it.
+    pub fn req_prop(&self, key: &'static str) -> Result<&'t Token, ViewError> {
+        self.prop(key).ok_or(ViewError::Missing {
+            token: Self::key(),
+            prop: key,
+        })
+    }
+
+    pub fn children(&self) -> &'t [Token] {
+        &self.token.children
+    }
+
+    /// The children that can be viewed as tokens of the parser `C`.
+    pub fn children_of<C: Parser>(&self) -> impl Iterator<Item = View<'t, C>> {
+        self.children()
+            .iter()
+            .filter_map(|child| View::<C>::try_from(child).ok())
+    }
+}
+
+impl<'t, P: Parser> TryFrom<&'t Token> for View<'t, P> {
+    type Error = ViewError;
+
+    fn try_from(token: &'t Token) -> Result<Self, Self::Error> {
+        let key = Self::key();
+        if token.tag(key) {
+            Ok(View {
+                token,
+                parser: PhantomData,
+            })
+        } else {
+            Err(ViewError::Mismatch {
+                expected: key,
+                found: token.name.clone(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{
+        results::builder::Builder,
+        tokens::expression::{
+            assignment::var::{self, Var},
+            literal::primitive::number::integer,
+        },
+    };
+
+    fn _integer(start: usize) -> Token {
+        Token::New()
+            .name(integer::KEY)
+            .tag(integer::KEY)
+            .build_from(start, start)
+    }
+
+    #[test]
+    fn props_are_viewed_by_key() {
+        let token = Token::New()
+            .name(var::KEY)
+            .tag(var::KEY)
+            .prop("key", Token::New().name("name").build_from(0, 4))
+            .prop("operator", Token::New().name("assigner").build_from(6, 7))
+            .prop("value", _integer(9))
+            .build_from(0, 9);
+
+        let var = Var::try_from(&token).unwrap();
+        assert_eq!((var.key.start, var.key.end), (0, 4));
+        assert_eq!((var.operator.start, var.operator.end), (6, 7));
+        assert_eq!(var.value.name, integer::KEY);
+    }
+
+    #[test]
+    fn missing_props_are_named() {
+        let token = Token::New()
+            .name(var::KEY)
+            .tag(var::KEY)
+            .prop("key", _integer(0))
+            .prop("value", _integer(4))
+            .build_from(0, 4);
+
+        assert_eq!(
+            Var::try_from(&token).unwrap_err(),
+            ViewError::Missing {
+                token: var::KEY,
+                prop: "operator"
+            }
+        );
+    }
+
+    #[test]
+    fn tokens_of_other_parsers_mismatch() {
+        let token = _integer(0);
+        assert_eq!(
+            Var::try_from(&token).unwrap_err(),
+            ViewError::Mismatch {
+                expected: var::KEY,
+                found: integer::KEY.to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn children_of_skips_other_tokens() {
+        let token = Token::New()
+            .name(var::KEY)
+            .tag(var::KEY)
+            .child(_integer(0))
+            .child(Token::New().name("word").tag("word").build_from(2, 2))
+            .child(_integer(4))
+            .build_from(0, 4);
+
+        let view = var::View::try_from(&token).unwrap();
+        let starts: Vec<usize> = view
+            .children_of::<integer::Parser>()
+            .map(|child| child.token.start)
+            .collect();
+        assert_eq!(starts, vec![0, 4]);
+    }
+}
@@ -0,0 +1,150 @@
+use super::{node::Node, token::Token};
+
+/// A step from a token to one of its children.
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
+pub enum Step {
+    /// A child that isn't a prop; by its index in the parent's children.
+    Index(usize),
+    /// A child that is a prop; by its key.
+    Prop(String),
+}
+
+/// The steps from a root token to one of its descendants.
+/// - Written like `/value/0/key`; or `/` for the root itself.
+#[derive(PartialEq, Eq, Debug, Clone, Hash, Default)]
+pub struct Path {
+    pub steps: Vec<Step>,
+}
+
+impl Path {
+    #[allow(non_snake_case)]
+    pub fn Root() -> Path {
+        Path { steps: Vec::new() }
+    }
+
+    /// The path to the child of this path's token with the given index.
+    pub fn child(&self, parent: &Token, index: usize) -> Path {
+        let mut steps = self.steps.clone();
+        steps.push(match parent.key(index) {
+            Some(key) => Step::Prop(key.clone()),
+            None => Step::Index(index),
+        });
+
+        Path { steps }
+    }
+
+    pub fn depth(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// The token at this path; starting from the given root.
+    pub fn resolve<'t>(&self, root: &'t Token) -> Option<&'t Token> {
+        let mut token = root;
+        for step in &self.steps {
+            token = match step {
+                Step::Index(index) => token.children.get(*index)?,
+                Step::Prop(key) => token.prop(key)?,
+            };
+        }
+
+        Some(token)
+    }
+}
+
+impl std::fmt::Display for Path {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.steps.is_empty() {
+            return write!(f, "/");
+        }
+
+        for step in &self.steps {
+            match step {
+                Step::Index(index) => write!(f, "/{}", index)?,
+                Step::Prop(key) => write!(f, "/{}", key)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// What a walk should do after visiting a token.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Visit {
+    /// Visit the token's children next.
+    Continue,
+    /// Skip the token's children.
+    Skip,
+    /// End the walk.
+    Stop,
+}
+
+/// Visits the tokens in a tree; depth first and in order.
+pub trait Visitor {
+    /// Called before visiting the token's children.
+    fn enter(&mut self, token: &Token, path: &Path) -> Visit;
+
+    /// Called after visiting the token's children; unless they were skipped or the walk was stopped.
+    fn exit(&mut self, _token: &Token, _path: &Path) {}
+}
+
+/// Rebuilds a tree of tokens; bottom up.
+pub trait Fold {
+    /// Folds a token; after its children have been folded.
+    /// - Returning `None` removes the token from its parent, along with its prop key if it had one.
+    fn fold(&mut self, token: Token, path: &Path) -> Option<Token>;
+}
+
+impl Token {
+    /// Walks the token and its descendants with the visitor; returning false if the walk was stopped.
+    pub fn walk(&self, visitor: &mut impl Visitor) -> bool {
+        self._walk(visitor, &Path::Root())
+    }
+
+    fn _walk(&self, visitor: &mut impl Visitor, path: &Path) -> bool {
+        match visitor.enter(self, path) {
+            Visit::Stop => return false,
+            Visit::Skip => return true,
+            Visit::Continue => {}
+        }
+
+        for (index, child) in self.children.iter().enumerate() {
+            if !child._walk(visitor, &path.child(self, index)) {
+                return false;
+            }
+        }
+
+        visitor.exit(self, path);
+        true
+    }
+
+    /// Folds the token and its descendants with the folder.
+    pub fn fold(self, folder: &mut impl Fold) -> Option<Token> {
+        self._fold(folder, &Path::Root())
+    }
+
+    fn _fold(mut self, folder: &mut impl Fold, path: &Path) -> Option<Token> {
+        let children = std::mem::take(&mut self.children);
+        let mut keys = std::collections::HashMap::new();
+        for (index, child) in children.into_iter().enumerate() {
+            let key = self.key(index).cloned();
+            if let Some(child) = child._fold(folder, &path.child(&self, index)) {
+                if let Some(key) = key {
+                    keys.insert(key, self.children.len());
+                }
+                self.children.push(child);
+            }
+        }
+
+        self.keys = if keys.is_empty() { None } else { Some(keys) };
+        folder.fold(self, path)
+    }
+}
+
+/// Any closure can be used to visit tokens; continuing through every token.
+impl<F: FnMut(&Token, &Path)> Visitor for F {
+    fn enter(&mut self, token: &Token, path: &Path) -> Visit {
+        self(token, path);
+        Visit::Continue
+    }
+}
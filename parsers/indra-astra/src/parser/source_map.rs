@@ -0,0 +1,198 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A position in the source; in each of the units tools and editors may use.
+/// - `line` and `column` start at 1; with columns counted in chars.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Position {
+    pub index: usize,
+    pub byte: usize,
+    pub utf16: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Converts positions in a source between char indices (as used by tokens and errors), UTF-8 byte offsets, UTF-16 code units, and lines/columns.
+pub struct SourceMap {
+    /// The byte offset of each char index; plus one for the end of the source.
+    bytes: Vec<usize>,
+    /// The UTF-16 offset of each char index; plus one for the end of the source.
+    utf16: Vec<usize>,
+    /// The char index at the start of each line.
+    lines: Vec<usize>,
+}
+
+impl SourceMap {
+    #[allow(non_snake_case)]
+    pub fn New(source: &str) -> SourceMap {
+        SourceMap::From_Chars(source.chars())
+    }
+
+    #[allow(non_snake_case)]
+    pub fn From_Chars<T: IntoIterator<Item = char>>(chars: T) -> SourceMap {
+        let mut bytes = vec![0];
+        let mut utf16 = vec![0];
+        let mut lines = vec![0];
+        for (index, c) in chars.into_iter().enumerate() {
+            bytes.push(bytes[index] + c.len_utf8());
+            utf16.push(utf16[index] + c.len_utf16());
+            if c == '\n' {
+                lines.push(index + 1);
+            }
+        }
+
+        SourceMap {
+            bytes,
+            utf16,
+            lines,
+        }
+    }
+
+    /// The number of chars in the source.
+    pub fn len(&self) -> usize {
+        self.bytes.len() - 1
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    // #region From Char Index
+    /// The byte offset of the char at the given index. (Indices past the end are clamped to the end of the source)
+    pub fn byte_at(&self, index: usize) -> usize {
+        self.bytes[index.min(self.len())]
+    }
+
+    /// The UTF-16 code unit offset of the char at the given index. (Indices past the end are clamped to the end of the source)
+    pub fn utf16_at(&self, index: usize) -> usize {
+        self.utf16[index.min(self.len())]
+    }
+
+    /// The (line, column) of the char at the given index. (Indices past the end are clamped to the end of the source)
+    pub fn line_col_at(&self, index: usize) -> (usize, usize) {
+        let index = index.min(self.len());
+        let line = self.lines.partition_point(|&start| start <= index) - 1;
+        (line + 1, index - self.lines[line] + 1)
+    }
+
+    pub fn position_at(&self, index: usize) -> Position {
+        let (line, column) = self.line_col_at(index);
+        Position {
+            index: index.min(self.len()),
+            byte: self.byte_at(index),
+            utf16: self.utf16_at(index),
+            line,
+            column,
+        }
+    }
+    // #endregion
+
+    // #region To Char Index
+    /// The index of the char starting at the given byte offset; if there is one.
+    pub fn index_of_byte(&self, byte: usize) -> Option<usize> {
+        self.bytes.binary_search(&byte).ok()
+    }
+
+    /// The index of the char starting at the given UTF-16 code unit offset; if there is one.
+    pub fn index_of_utf16(&self, utf16: usize) -> Option<usize> {
+        self.utf16.binary_search(&utf16).ok()
+    }
+
+    /// The index of the char at the given (line, column); if there is one.
+    pub fn index_of_line_col(&self, line: usize, column: usize) -> Option<usize> {
+        if line == 0 || column == 0 {
+            return None;
+        }
+
+        let start = *self.lines.get(line - 1)?;
+        let end = match self.lines.get(line) {
+            Some(next) => next - 1,
+            None => self.len(),
+        };
+
+        let index = start + column - 1;
+        if index <= end {
+            Some(index)
+        } else {
+            None
+        }
+    }
+    // #endregion
+
+    /// Adds the converted `positions` of the start and end of every token and error in the given json output.
+    pub fn annotate_json(&self, value: &mut Value) {
+        match value {
+            Value::Object(fields) => {
+                for (_, field) in fields.iter_mut() {
+                    self.annotate_json(field);
+                }
+
+                if let (Some(Value::Number(start)), Some(Value::Number(end))) =
+                    (fields.get("start"), fields.get("end"))
+                {
+                    if let (Some(start), Some(end)) = (start.as_u64(), end.as_u64()) {
+                        let positions = serde_json::json!({
+                            "start": self.position_at(start as usize),
+                            "end": self.position_at(end as usize),
+                        });
+
+                        fields.insert("positions".to_string(), positions);
+                    }
+                }
+            }
+            Value::Array(items) => {
+                for item in items.iter_mut() {
+                    self.annotate_json(item);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Chars of one, two and four UTF-8 bytes; the last also being two UTF-16 code units.
+    const SOURCE: &str = "aé\n𝄞b\n";
+
+    #[test]
+    fn converts_char_indices_with_multibyte_chars() {
+        let map = SourceMap::New(SOURCE);
+        assert_eq!(map.len(), 6);
+        assert_eq!(map.line_count(), 3);
+
+        let bytes: Vec<usize> = (0..=6).map(|index| map.byte_at(index)).collect();
+        assert_eq!(bytes, vec![0, 1, 3, 4, 8, 9, 10]);
+        let utf16: Vec<usize> = (0..=6).map(|index| map.utf16_at(index)).collect();
+        assert_eq!(utf16, vec![0, 1, 2, 3, 5, 6, 7]);
+
+        assert_eq!(map.line_col_at(2), (1, 3));
+        assert_eq!(map.line_col_at(4), (2, 2));
+        assert_eq!(map.line_col_at(6), (3, 1));
+        assert_eq!(map.byte_at(100), 10);
+    }
+
+    #[test]
+    fn converts_back_to_char_indices() {
+        let map = SourceMap::New(SOURCE);
+        for index in 0..=6 {
+            let (line, column) = map.line_col_at(index);
+            assert_eq!(map.index_of_line_col(line, column), Some(index));
+            assert_eq!(map.index_of_byte(map.byte_at(index)), Some(index));
+            assert_eq!(map.index_of_utf16(map.utf16_at(index)), Some(index));
+        }
+    }
+
+    #[test]
+    fn offsets_inside_a_char_or_past_a_line_have_no_index() {
+        let map = SourceMap::New(SOURCE);
+        assert_eq!(map.index_of_byte(2), None);
+        assert_eq!(map.index_of_byte(5), None);
+        assert_eq!(map.index_of_utf16(4), None);
+        assert_eq!(map.index_of_line_col(1, 4), None);
+        assert_eq!(map.index_of_line_col(4, 1), None);
+        assert_eq!(map.index_of_line_col(0, 1), None);
+    }
+}
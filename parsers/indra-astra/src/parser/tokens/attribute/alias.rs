@@ -1,3 +1,4 @@
+use crate::parser::results::{token::Token, view::ViewError};
 use crate::parser::tokens::{expression::identifier::key::name, token};
 
 token! {
@@ -25,3 +26,26 @@ token! {
           .build_from(1, 5)
         ).build_from(0, 5)))
 }
+
+/// A typed view of an alias attribute; like `|alias`.
+#[derive(Debug, Clone, Copy)]
+pub struct Alias<'t> {
+    pub token: &'t Token,
+    /// The attribute expression following the prefix.
+    pub expression: &'t Token,
+}
+
+impl<'t> TryFrom<&'t Token> for Alias<'t> {
+    type Error = ViewError;
+
+    fn try_from(token: &'t Token) -> Result<Self, Self::Error> {
+        let view = View::try_from(token)?;
+        Ok(Alias {
+            token,
+            expression: view.children().first().ok_or(ViewError::Missing {
+                token: KEY,
+                prop: "expression",
+            })?,
+        })
+    }
+}
@@ -1,3 +1,4 @@
+use crate::parser::results::{token::Token, view::ViewError};
 use crate::parser::tokens::{expression::identifier::key::name, token};
 
 token! {
@@ -30,3 +31,26 @@ token! {
           .build_from(1, 5)
         ).build_from(0, 5)))
 }
+
+/// A typed view of an input attribute; like `>input`.
+#[derive(Debug, Clone, Copy)]
+pub struct Input<'t> {
+    pub token: &'t Token,
+    /// The attribute expression following the prefix.
+    pub expression: &'t Token,
+}
+
+impl<'t> TryFrom<&'t Token> for Input<'t> {
+    type Error = ViewError;
+
+    fn try_from(token: &'t Token) -> Result<Self, Self::Error> {
+        let view = View::try_from(token)?;
+        Ok(Input {
+            token,
+            expression: view.children().first().ok_or(ViewError::Missing {
+                token: KEY,
+                prop: "expression",
+            })?,
+        })
+    }
+}
@@ -1,4 +1,10 @@
-use crate::{parser::tokens::attribute, runtime::nodes::Key};
+use crate::{
+    parser::{
+        results::{token::Token, view::ViewError},
+        tokens::attribute,
+    },
+    runtime::nodes::Key,
+};
 
 use super::{splay_mods, token};
 
@@ -34,3 +40,36 @@ token! {
     },
     subs: [tag, alias, input]
 }
+
+/// A typed view of any kind of attribute.
+#[derive(Debug, Clone, Copy)]
+pub enum Attribute<'t> {
+    Tag(tag::Tag<'t>),
+    Alias(alias::Alias<'t>),
+    Input(input::Input<'t>),
+}
+
+impl<'t> TryFrom<&'t Token> for Attribute<'t> {
+    type Error = ViewError;
+
+    fn try_from(token: &'t Token) -> Result<Self, Self::Error> {
+        match token.name.as_str() {
+            tag::KEY => Ok(Attribute::Tag(tag::Tag::try_from(token)?)),
+            alias::KEY => Ok(Attribute::Alias(alias::Alias::try_from(token)?)),
+            input::KEY => Ok(Attribute::Input(input::Input::try_from(token)?)),
+            _ => Err(ViewError::Mismatch {
+                expected: KEY,
+                found: token.name.clone(),
+            }),
+        }
+    }
+}
+
+/// The attributes in the given groups of attributes; like `attribute_group` or `trailing_attributes` tokens.
+pub fn all_in<'t>(groups: impl Iterator<Item = &'t Token>) -> Vec<Attribute<'t>> {
+    groups
+        .filter(|group| group.name == group::KEY || group.name == trailing::KEY)
+        .flat_map(|group| group.children.iter())
+        .filter_map(|attribute| Attribute::try_from(attribute).ok())
+        .collect()
+}
@@ -1,11 +1,29 @@
-use crate::parser::tokens::{expression::identifier::key::name, token};
+use crate::parser::results::{token::Token, view::ViewError};
+use crate::parser::tokens::{
+    expression::{
+        identifier::key::name,
+        literal::{primitive::number::integer, structure::closure},
+    },
+    token,
+};
 
 token! {
   tag => |cursor: &mut Cursor| {
     match cursor.curr() {
       '#' => {
         cursor.read();
-        End::Child::<tokens::expression::attribute_expression::Parser>(&KEY, cursor)
+        match tokens::expression::attribute_expression::Parser::Parse_At(cursor) {
+          Parsed::Pass(expression) => {
+            let result = Token::New().child(expression);
+            // arguments; like the `(3)` in `#size(3)`
+            if closure::is_opener(cursor.curr()) {
+              End::Prop_Of::<closure::Parser>(result, "args", cursor)
+            } else {
+              result.to_end()
+            }
+          },
+          Parsed::Fail(error) => End::Unexpected_Child_Of(Token::New(), error),
+        }
       },
       '>' => match cursor.next() {
         '#' => End::ToDo("read as input-tag"),
@@ -28,4 +46,47 @@ token! {
           .partial()
           .build_from(1, 3)
         ).build_from(0, 3)))
+    unit!(["Named" & "Args"]
+      : "#size(3)"
+      => Parsed::Pass(Token::New()
+        .name(&KEY)
+        .child(Token::New()
+          .name(name::KEY)
+          .partial()
+          .build_from(1, 4))
+        .prop("args", Token::New()
+          .name(closure::group::KEY)
+          .partial()
+          .child(Token::New()
+            .name(integer::KEY)
+            .partial()
+            .build_from(6, 6))
+          .build_from(5, 7))
+        .build_from(0, 7)))
+}
+
+/// A typed view of a tag attribute; like `#tag`.
+#[derive(Debug, Clone, Copy)]
+pub struct Tag<'t> {
+    pub token: &'t Token,
+    /// The attribute expression following the prefix.
+    pub expression: &'t Token,
+    /// The closure right after the expression; like the `(3)` in `#size(3)`.
+    pub args: Option<&'t Token>,
+}
+
+impl<'t> TryFrom<&'t Token> for Tag<'t> {
+    type Error = ViewError;
+
+    fn try_from(token: &'t Token) -> Result<Self, Self::Error> {
+        let view = View::try_from(token)?;
+        Ok(Tag {
+            token,
+            expression: view.children().first().ok_or(ViewError::Missing {
+                token: KEY,
+                prop: "expression",
+            })?,
+            args: view.prop("args"),
+        })
+    }
 }
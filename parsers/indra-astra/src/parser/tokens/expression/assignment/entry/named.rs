@@ -1,8 +1,8 @@
 use crate::parser::{
     cursor::Cursor,
-    results::{token::Token, token_builder::TokenBuilder},
+    results::{token::Token, token_builder::TokenBuilder, view::ViewError},
     tokens::{
-        attribute,
+        attribute::{self, Attribute},
         expression::{self, identifier::key, value_expression},
         expression::{identifier::key::name, literal::structure::tree},
         statement::branch,
@@ -14,6 +14,7 @@ use crate::parser::{
 
 token! {
     named_entry => |cursor: &mut Cursor | {
+        let initial_indent = cursor.curr_indent();
         let mut result = Token::New();
 
         // pre-key attributes
@@ -44,6 +45,10 @@ token! {
                         cursor.restore();
                         return result.end(cursor.prev_non_ws_pos()).to_end();
                     }
+                    Indents::Error(error) => {
+                        cursor.pop();
+                        return End::Error_In_Child_Of(result, Some(error));
+                    }
                     _ => {
                         cursor.pop();
                         cursor.skip_ws();
@@ -71,6 +76,10 @@ token! {
                                 cursor.restore();
                                 return result.end(cursor.prev_non_ws_pos()).to_end();
                             }
+                            Indents::Error(error) => {
+                                cursor.pop();
+                                return End::Error_In_Child_Of(result, Some(error));
+                            }
                             _ => {
                                 cursor.skip_ws();
                             }
@@ -109,6 +118,10 @@ token! {
                                 cursor.restore();
                                 return result.end(cursor.prev_non_ws_pos()).to_end();
                             }
+                            Indents::Error(error) => {
+                                cursor.pop();
+                                return End::Error_In_Child_Of(result, Some(error));
+                            }
                             _ => {}
                         }
                         cursor.pop();
@@ -133,6 +146,18 @@ token! {
 
                                 return result.end(cursor.prev_non_ws_pos()).to_end();
                             }
+                            Parsed::Fail(error) if cursor.is_recovering() => {
+                                let error = error.unwrap_or_else(|| {
+                                    Error::New(&format!("missing_expected_value_in_{}", KEY))
+                                        .text(&format!("Expected: `value`, but found: `{}`.", cursor.curr_str()))
+                                        .tag("missing")
+                                        .build_from(cursor.curr_pos(), cursor.curr_pos())
+                                        .unwrap()
+                                });
+
+                                result.set_prop("value", cursor.recover(error, initial_indent));
+                                return result.end(cursor.prev_non_ws_pos()).to_end();
+                            }
                             Parsed::Fail(error) => return End::Error_In_Prop_Of(result, "value", error),
                         }
                     }
@@ -209,7 +234,39 @@ token! {
                 .prop("operator", Mock::Sub::<assigner::field::Parser>())
                 .child(Mock::Sub::<indent::increase::Parser>())
                 .prop("value", Mock::Sub::<expression::Parser>()))
+        unit!(["Two Lines" & "Unclosed Group After Indent"]
+            : "a: :\n\t(a"
+            => Parsed::Fail(Some(Error::New("incomplete_named_entry")
+                .tag("incomplete")
+                .partial()
+                .build_from(0, 2)
+                .unwrap())))
+}
+
+/// A typed view of a named entry; like `#tag key: value`.
+#[derive(Debug, Clone)]
+pub struct NamedEntry<'t> {
+    pub token: &'t Token,
+    pub key: &'t Token,
+    /// The assigner; if the entry has a value.
+    pub operator: Option<&'t Token>,
+    pub value: Option<&'t Token>,
+    pub attributes: Vec<Attribute<'t>>,
+}
+
+impl<'t> TryFrom<&'t Token> for NamedEntry<'t> {
+    type Error = ViewError;
 
+    fn try_from(token: &'t Token) -> Result<Self, Self::Error> {
+        let view = View::try_from(token)?;
+        Ok(NamedEntry {
+            token,
+            key: view.req_prop("key")?,
+            operator: view.prop("operator"),
+            value: view.prop("value"),
+            attributes: attribute::all_in(view.children().iter()),
+        })
+    }
 }
 
 // fn check_for_attrs(result: &mut TokenBuilder, cursor: &mut Cursor) -> Option<bool> {
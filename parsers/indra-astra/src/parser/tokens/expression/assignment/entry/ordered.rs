@@ -1,7 +1,130 @@
-use crate::parser::tokens::token;
+use crate::parser::{
+    cursor::Cursor,
+    results::{token::Token, view::ViewError},
+    tokens::{
+        attribute::{self, Attribute},
+        expression::{
+            assignment,
+            literal::{markup::word, primitive::number::integer, structure::tree},
+        },
+        token,
+    },
+};
+
+/// The prefix of an ordered entry; like the `-` in `- value`.
+pub const DASH: char = '-';
 
 token! {
   ordered_entry => |cursor: &mut Cursor| {
-     End::Not_Implemented()
-  }
+        let mut result = Token::New();
+
+        // pre-dash attributes
+        cursor.save();
+        if let Some(preceeding_attributes) = attribute::group::Parser::Try_Parse_At(cursor) {
+            result.add_child(preceeding_attributes);
+        }
+
+        // dash
+        if !is_dash_at(cursor) {
+            cursor.restore();
+            return End::None;
+        }
+        cursor.pop();
+        cursor.read();
+        cursor.skip_spacing();
+
+        // post-dash attributes
+        if let Some(attrs) = attribute::trailing::Parser::Try_Parse_At(cursor) {
+            result.add_child(attrs);
+            cursor.skip_spacing();
+        }
+
+        // value
+        assignment::parse_rhs(cursor, result, "value")
+    },
+    tests:
+        unit!(["One Line"]
+            : "- 1"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .prop("value", Token::New()
+                    .name(integer::KEY)
+                    .partial()
+                    .build_from(2, 2))
+                .build_from(0, 2)))
+        unit!(["One Line" & "Attribute Before Dash"]
+            : "#first - a"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .child(Token::New()
+                    .name(attribute::group::KEY)
+                    .partial()
+                    .build_from(0, 5))
+                .prop("value", Token::New()
+                    .name(word::KEY)
+                    .partial()
+                    .build_from(9, 9))
+                .build_from(0, 9)))
+        unit!(["One Line" & "Attribute After Dash"]
+            : "- #first a"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .child(Token::New()
+                    .name(attribute::trailing::KEY)
+                    .partial()
+                    .build_from(2, 7))
+                .prop("value", Token::New()
+                    .name(word::KEY)
+                    .partial()
+                    .build_from(9, 9))
+                .build_from(0, 9)))
+        unit!(["Two Lines" & "Nested"]
+            : "-\n\t- a"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .partial()
+                .prop("value", Token::New()
+                    .name(tree::KEY)
+                    .partial()
+                    .build_from(3, 5))
+                .build_from(0, 5)))
+        unit!(["Two Lines" & "Empty"]
+            : "-\n- b"
+            => Parsed::Fail(Some(Error::New("incomplete_ordered_entry")
+                .tag("incomplete")
+                .partial()
+                .build_from(0, 0)
+                .unwrap())))
+        unit!(["Negative Number"]
+            : "-1"
+            => Parsed::Fail(None))
+        unit!(["Nul After Dash"]
+            : "-\0"
+            => Parsed::Fail(None))
+}
+
+/// If the cursor is at the dash of an ordered entry; which must be followed by whitespace to not be a negative number or a word.
+pub fn is_dash_at(cursor: &Cursor) -> bool {
+    cursor.curr_is(DASH) && (cursor.next_is_ws() || cursor.eof_at(cursor.next_pos()))
+}
+
+/// A typed view of an ordered entry; like `- value`.
+#[derive(Debug, Clone)]
+pub struct OrderedEntry<'t> {
+    pub token: &'t Token,
+    pub value: &'t Token,
+    pub attributes: Vec<Attribute<'t>>,
+}
+
+impl<'t> TryFrom<&'t Token> for OrderedEntry<'t> {
+    type Error = ViewError;
+
+    fn try_from(token: &'t Token) -> Result<Self, Self::Error> {
+        let view = View::try_from(token)?;
+        Ok(OrderedEntry {
+            token,
+            value: view.req_prop("value")?,
+            attributes: attribute::all_in(view.children().iter()),
+        })
+    }
 }
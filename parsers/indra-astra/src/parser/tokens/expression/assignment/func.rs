@@ -1,7 +1,143 @@
-use crate::parser::tokens::token;
+use crate::parser::{
+    results::{token::Token, view::ViewError},
+    tokens::{
+        attribute::input::{self, Input},
+        expression::{
+            assignment,
+            identifier::key,
+            literal::{markup::word, structure::tree},
+        },
+        symbol::operator::assigner,
+        token,
+    },
+};
 
 token! {
     func => |cursor: &mut Cursor| {
-        End::Not_Implemented()
+        let mut result = Token::New();
+
+        // key
+        match key::Parser::Parse_Opt_At(cursor) {
+            Parsed::Pass(key) => {
+                result.set_prop("key", key);
+            }
+            Parsed::Fail(_) => return End::None,
+        }
+
+        // params
+        cursor.skip_spacing();
+        while cursor.curr_is('>') {
+            match input::Parser::Parse_Opt_At(cursor) {
+                Parsed::Pass(param) => {
+                    result.add_child(param);
+                    cursor.skip_spacing();
+                }
+                Parsed::Fail(_) => return End::None,
+            }
+        }
+
+        // operator
+        let operator = match assigner::func::Parser::Parse_Opt_At(cursor) {
+            Parsed::Pass(operator) => operator,
+            Parsed::Fail(_) => return End::None,
+        };
+
+        if operator.tag(assigner::func::MUT_TAG) {
+            result.add_tag(assigner::func::MUT_TAG);
+        } else {
+            result.add_tag(assigner::func::CONST_TAG);
+        }
+        result.set_prop("operator", operator);
+
+        // body
+        assignment::parse_rhs(cursor, result, "body")
+    },
+    tests:
+        unit!(["Inline Body"]
+            : "greet => hello"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .tag(assigner::func::CONST_TAG)
+                .prop("key", Token::New()
+                    .name(key::name::KEY)
+                    .partial()
+                    .build_from(0, 4))
+                .prop("operator", Token::New()
+                    .name(assigner::func::KEY)
+                    .partial()
+                    .build_from(6, 7))
+                .prop("body", Token::New()
+                    .name(word::KEY)
+                    .partial()
+                    .build_from(9, 13))
+                .build_from(0, 13)))
+        unit!(["Params" & "Mutable"]
+            : "add >a >b ~> a"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .tag(assigner::func::MUT_TAG)
+                .prop("key", Token::New()
+                    .name(key::name::KEY)
+                    .partial()
+                    .build_from(0, 2))
+                .child(Token::New()
+                    .name(input::KEY)
+                    .partial()
+                    .build_from(4, 5))
+                .child(Token::New()
+                    .name(input::KEY)
+                    .partial()
+                    .build_from(7, 8))
+                .prop("operator", Token::New()
+                    .name(assigner::func::KEY)
+                    .partial()
+                    .build_from(10, 11))
+                .prop("body", Token::New()
+                    .name(word::KEY)
+                    .partial()
+                    .build_from(13, 13))
+                .build_from(0, 13)))
+        unit!(["Tree Body"]
+            : "run >x =>\n\ta: x"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .partial()
+                .prop("body", Token::New()
+                    .name(tree::KEY)
+                    .partial()
+                    .build_from(11, 14))
+                .build_from(0, 14)))
+        unit!(["Not A Func"]
+            : "a = 1"
+            => Parsed::Fail(None))
+}
+
+/// A typed view of a function assignment; like `add >a >b => a`.
+#[derive(Debug, Clone)]
+pub struct Func<'t> {
+    pub token: &'t Token,
+    pub key: &'t Token,
+    /// The `>input` attributes between the key and the assigner.
+    pub params: Vec<Input<'t>>,
+    pub operator: &'t Token,
+    pub body: &'t Token,
+}
+
+impl<'t> TryFrom<&'t Token> for Func<'t> {
+    type Error = ViewError;
+
+    fn try_from(token: &'t Token) -> Result<Self, Self::Error> {
+        let view = View::try_from(token)?;
+        Ok(Func {
+            token,
+            key: view.req_prop("key")?,
+            params: view
+                .children()
+                .iter()
+                .filter_map(|child| Input::try_from(child).ok())
+                .collect(),
+            operator: view.req_prop("operator")?,
+            body: view.req_prop("body")?,
+        })
     }
 }
@@ -1,5 +1,56 @@
-use crate::parser::tokens::splay_mods;
+use crate::parser::{
+    cursor::Cursor,
+    results::{builder::Builder, end::End, parsed::Parsed, token_builder::TokenBuilder},
+    tokens::{
+        expression::{literal::structure::tree, value_expression},
+        splay_mods,
+        whitespace::indent::{self, Indents},
+    },
+    Parser as _,
+};
 
 splay_mods! {
     assignment: [var, func, entry]
 }
+
+/// Reads the right hand side of an assignment after its operator (or dash); setting it as the given prop of the result.
+/// - It's read as a tree if it starts on a further indented line; or as a value expression otherwise.
+/// - It can't start on a line that isn't further indented; which leaves the assignment incomplete.
+pub fn parse_rhs(cursor: &mut Cursor, mut result: TokenBuilder, key: &str) -> End {
+    // post-operator indent
+    let base_indent = cursor.curr_indent();
+    cursor.skip_spacing();
+    cursor.save();
+    match indent::Parse_Opt_Or_Skip_At(cursor) {
+        Indents::Increase(token) => {
+            cursor.pop();
+            result.add_child(token);
+        }
+        Indents::Error(error) => {
+            cursor.pop();
+            return End::Error_In_Child_Of(result, Some(error));
+        }
+        Indents::None => {
+            cursor.pop();
+        }
+        _ => {
+            cursor.restore();
+            return End::Error_In_Prop_Of(result, key, None);
+        }
+    }
+
+    // value
+    let value = if cursor.curr_indent() > base_indent {
+        tree::Parser::Parse_Opt_At(cursor)
+    } else {
+        value_expression::Parser::Parse_Opt_At(cursor)
+    };
+
+    match value {
+        Parsed::Pass(value) => {
+            result.set_prop(key, value);
+            result.end(cursor.prev_non_ws_pos()).to_end()
+        }
+        Parsed::Fail(error) => End::Error_In_Prop_Of(result, key, error),
+    }
+}
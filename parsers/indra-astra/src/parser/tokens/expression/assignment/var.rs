@@ -1,7 +1,157 @@
-use crate::parser::tokens::token;
+use crate::parser::{
+    indents::{IndentStyle, Indentation},
+    results::{token::Token, view::ViewError},
+    tokens::{
+        expression::{
+            assignment,
+            identifier::key,
+            literal::{markup::word, primitive::number::integer, structure::tree},
+        },
+        symbol::operator::assigner,
+        token,
+    },
+};
 
 token! {
     var => |cursor: &mut Cursor| {
-        End::Not_Implemented()
+        let mut result = Token::New();
+
+        // key
+        match key::Parser::Parse_Opt_At(cursor) {
+            Parsed::Pass(key) => {
+                result.set_prop("key", key);
+            }
+            Parsed::Fail(_) => return End::None,
+        }
+
+        // operator
+        cursor.skip_spacing();
+        let operator = match assigner::var::Parser::Parse_Opt_At(cursor) {
+            Parsed::Pass(operator) => operator,
+            Parsed::Fail(_) => return End::None,
+        };
+
+        if operator.tag(assigner::var::MUT_TAG) {
+            result.add_tag(assigner::var::MUT_TAG);
+        } else {
+            result.add_tag(assigner::var::CONST_TAG);
+        }
+        result.set_prop("operator", operator);
+
+        // value
+        assignment::parse_rhs(cursor, result, "value")
+    },
+    tests:
+        unit!(["Constant"]
+            : "a = 1"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .tag(assigner::var::CONST_TAG)
+                .prop("key", Token::New()
+                    .name(key::name::KEY)
+                    .partial()
+                    .build_from(0, 0))
+                .prop("operator", Token::New()
+                    .name(assigner::var::KEY)
+                    .partial()
+                    .build_from(2, 2))
+                .prop("value", Token::New()
+                    .name(integer::KEY)
+                    .partial()
+                    .build_from(4, 4))
+                .build_from(0, 4)))
+        unit!(["Mutable"]
+            : "count ~= total"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .tag(assigner::var::MUT_TAG)
+                .prop("key", Token::New()
+                    .name(key::name::KEY)
+                    .partial()
+                    .build_from(0, 4))
+                .prop("operator", Token::New()
+                    .name(assigner::var::KEY)
+                    .partial()
+                    .build_from(6, 7))
+                .prop("value", Token::New()
+                    .name(word::KEY)
+                    .partial()
+                    .build_from(9, 13))
+                .build_from(0, 13)))
+        unit!(["Tree Value"]
+            : "a =\n\tb: 1"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .partial()
+                .prop("value", Token::New()
+                    .name(tree::KEY)
+                    .partial()
+                    .build_from(5, 8))
+                .build_from(0, 8)))
+        unit!(["Tree Value" & "Mixed Indent After Decrease"]
+            : "a =\n\tb:\n\t\tc: 1\n\td:\n\t\t e: 2"
+            => Parsed::Fail(Some(Error::New("incomplete_var")
+                .tag("incomplete")
+                .partial()
+                .build_from(0, 4)
+                .unwrap())),
+            indentation: Indentation::New(IndentStyle::Detect, 4))
+        unit!(["Tree Value" & "Tab Width"]
+            : "a =\n\tb: 1\n  c: 2"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .partial()
+                .prop("value", Token::New()
+                    .name(tree::KEY)
+                    .partial()
+                    .build_from(5, 15))
+                .build_from(0, 15)),
+            indentation: Indentation::New(IndentStyle::Any, 2))
+        unit!(["Tree Value" & "Narrower Than Tab Width"]
+            : "a =\n\tb: 1\n  c: 2"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .partial()
+                .prop("value", Token::New()
+                    .name(tree::KEY)
+                    .partial()
+                    .build_from(5, 8))
+                .build_from(0, 8)),
+            indentation: Indentation::New(IndentStyle::Any, 4))
+        unit!(["Tree Value" & "Default Tab Width"]
+            : "a =\n\tb: 1\n c: 2"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .partial()
+                .prop("value", Token::New()
+                    .name(tree::KEY)
+                    .partial()
+                    .build_from(5, 14))
+                .build_from(0, 14)))
+        unit!(["Not A Var"]
+            : "a: 1"
+            => Parsed::Fail(None))
+}
+
+/// A typed view of a variable assignment; like `count ~= 1`.
+#[derive(Debug, Clone, Copy)]
+pub struct Var<'t> {
+    pub token: &'t Token,
+    pub key: &'t Token,
+    pub operator: &'t Token,
+    pub value: &'t Token,
+}
+
+impl<'t> TryFrom<&'t Token> for Var<'t> {
+    type Error = ViewError;
+
+    fn try_from(token: &'t Token) -> Result<Self, Self::Error> {
+        let view = View::try_from(token)?;
+        Ok(Var {
+            token,
+            key: view.req_prop("key")?,
+            operator: view.req_prop("operator")?,
+            value: view.req_prop("value")?,
+        })
     }
 }
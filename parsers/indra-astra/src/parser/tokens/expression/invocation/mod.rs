@@ -1,7 +1,6 @@
 use crate::parser::tokens::splay_mods;
 
-pub mod prefixed;
-
 splay_mods! {
     invocation: [lookup]
+    subs: [lookup, prefixed]
 }
@@ -1,7 +1,174 @@
-use crate::parser::tokens::token;
+use crate::parser::{
+    cursor::Cursor,
+    results::{builder::Builder, parsed::Parsed, token::Token},
+    tokens::{
+        expression::{
+            identifier::key::name,
+            invocation::lookup::{self, dot_lookup, slash_lookup},
+            literal::{markup::word, primitive::number::integer, structure::closure},
+            value_expression,
+        },
+        token,
+    },
+    Parser as _,
+};
+
+/// Calls its target with the argument after it; like the `:` in `print:"hi"` or `print: "hi"`.
+/// - Unlike the field assigner, it can't have whitespace before it.
+/// - `name: value` also reads as a named entry; which the `assignment` splay tries first, wherever both could be read.
+pub const CALLER: char = ':';
 
 token! {
     prefixed_invokation => |cursor: &mut Cursor| {
-        End::Not_Implemented()
+        let target = match _read_target(cursor) {
+            Some(target) => target,
+            None => return End::None,
+        };
+
+        // caller
+        if !cursor.curr_is(CALLER) {
+            return End::None;
+        }
+
+        // `::` is an assigner, and `://` is the scheme of a url.
+        let next = cursor.next();
+        if next == CALLER || (next == '/' && cursor.ahead(2) == '/') {
+            return End::None;
+        }
+        cursor.read();
+        cursor.skip_spacing();
+
+        // a caller at the end of a line starts an entry's tree instead.
+        if cursor.is_eof() || cursor.curr_is_ws() || cursor.curr_is_closer() {
+            return End::None;
+        }
+
+        // args
+        let args = match value_expression::_try_to_read_value_expression_element(cursor) {
+            Ok(Some(args)) => args,
+            Ok(None) => return End::Missing("args", "an argument", &cursor.curr_str()),
+            Err(error) => return End::Error_In_Prop_Of(Token::New().prop("target", target), "args", Some(error)),
+        };
+
+        Token::New()
+            .prop("target", target)
+            .prop("args", args)
+            .to_end()
+    },
+    tests:
+        unit!(["Name"]
+            : "print:hello"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .prop("target", Token::New()
+                    .name(name::KEY)
+                    .partial()
+                    .build_from(0, 4))
+                .prop("args", Token::New()
+                    .name(word::KEY)
+                    .partial()
+                    .build_from(6, 10))
+                .build_from(0, 10)))
+        unit!(["Lookups" & "Closure"]
+            : "math.max/of:(1, 2)"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .prop("target", Token::New()
+                    .name(slash_lookup::KEY)
+                    .partial()
+                    .prop("target", Token::New()
+                        .name(dot_lookup::KEY)
+                        .partial()
+                        .prop("target", Token::New()
+                            .name(name::KEY)
+                            .partial()
+                            .build_from(0, 3))
+                        .build_from(0, 7))
+                    .build_from(0, 10))
+                .prop("args", Token::New()
+                    .name(closure::group::KEY)
+                    .partial()
+                    .build_from(12, 17))
+                .build_from(0, 17)))
+        unit!(["Chained"]
+            : "a:b:1"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .partial()
+                .prop("args", Token::New()
+                    .name(&KEY)
+                    .partial()
+                    .prop("args", Token::New()
+                        .name(integer::KEY)
+                        .partial()
+                        .build_from(4, 4))
+                    .build_from(2, 4))
+                .build_from(0, 4)))
+        unit!(["Spaced"]
+            : "a: b"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .prop("target", Token::New()
+                    .name(name::KEY)
+                    .partial()
+                    .build_from(0, 0))
+                .prop("args", Token::New()
+                    .name(word::KEY)
+                    .partial()
+                    .build_from(3, 3))
+                .build_from(0, 3)))
+        unit!(["Spaced" & "Chained"]
+            : "a:\tb: 1"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .partial()
+                .prop("args", Token::New()
+                    .name(&KEY)
+                    .partial()
+                    .prop("args", Token::New()
+                        .name(integer::KEY)
+                        .partial()
+                        .build_from(6, 6))
+                    .build_from(3, 6))
+                .build_from(0, 6)))
+        unit!(["Trailing Caller"]
+            : "a: \nb"
+            => Parsed::Fail(None))
+        unit!(["Url"]
+            : "https://example.com"
+            => Parsed::Fail(None))
+        unit!(["Nul Argument"]
+            : "a:\0"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .partial()
+                .prop("args", Token::New()
+                    .name(word::KEY)
+                    .partial()
+                    .build_from(2, 2))
+                .build_from(0, 2)))
+}
+
+/// Reads the target of an invocation; a name or lookup, followed by any lookups chained onto it.
+/// - Each lookup in the chain has what it looks up on as its `target` prop.
+fn _read_target(cursor: &mut Cursor) -> Option<Token> {
+    let mut target = match name::Parser::Try_Parse_At(cursor) {
+        Some(name) => name,
+        None => lookup::Parser::Try_Parse_At(cursor)?,
+    };
+
+    while cursor.curr_is('.') || cursor.curr_is('/') {
+        match lookup::Parser::Try_Parse_At(cursor) {
+            Some(lookup) => {
+                let start = target.start;
+                target = lookup
+                    .as_builder()
+                    .prop("target", target)
+                    .build_from(start, cursor.prev_pos());
+            }
+            None => break,
+        }
     }
+
+    Some(target)
 }
@@ -15,9 +15,7 @@ use crate::parser::tokens::{
 token! {
     paragraph => |cursor: &mut Cursor| {
         let mut result = Token::Of_Type::<paragraph::Parser>();
-        let mut start = cursor.curr_pos();
         let initial_indent = cursor.curr_indent();
-        let mut ws_only = true;
 
         'pg: loop {
             if cursor.is_eof() {
@@ -26,7 +24,6 @@ token! {
 
             if let Some(escape) = escape_sequence::Parser::Try_Parse_At(cursor) {
                 result.add_child(escape);
-                ws_only = false;
             } else {
                 if cursor.curr_is_ws() {
                     let mut found_nl = false;
@@ -51,11 +48,8 @@ token! {
                         cursor.skip();
                     }
 
-                    if ws_only {
-                        start = cursor.curr_pos();
-                    }
-
-                    if found_nl && cursor.curr_indent() <= initial_indent  {
+                    // lines with mixed indentation are left for the indent parser to report.
+                    if found_nl && (cursor.curr_indent() <= initial_indent || cursor.indent().mixed.is_some()) {
                         cursor.restore();
                         break;
                     } else {
@@ -65,9 +59,9 @@ token! {
                 } else {
                     if let Some(sentence) = sentence::Parser::Try_Parse_At(cursor) {
                         result.add_child(sentence);
-                        ws_only = false;
                     } else {
-                        End::Unexpected("in-paragraph", &cursor.slice(start, cursor.prev_pos()));
+                        // nothing else can be read here, so the paragraph ends.
+                        break;
                     }
                 }
             }
@@ -76,7 +70,22 @@ token! {
         if result.children.is_none() {
             return End::None;
         } else {
-            return result.end(cursor.prev_non_ws_pos()).to_end();
+            // the last sentence may have been read before whitespace the paragraph gave back.
+            let last = result.children.as_ref().and_then(|children| children.last()).map_or(0, |child| child.end);
+            return result.end(cursor.prev_non_ws_pos().max(last)).to_end();
         }
-    }
+    },
+    tests:
+        unit!(["Unreadable Char"]
+            : ".x"
+            => Parsed::Fail(None))
+        unit!(["Sentence Before Unreadable Char"]
+            : "a .x"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .child(Token::New()
+                    .name(sentence::KEY)
+                    .partial()
+                    .build_from(0, 0))
+                .build_from(0, 0)))
 }
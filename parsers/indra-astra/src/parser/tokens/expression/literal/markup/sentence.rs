@@ -37,6 +37,11 @@ token! {
 
       only_ws = false;
 
+      // the end of the delimited expression the sentence is in; like the closing quote of a string.
+      if cursor.curr_is_closer() {
+        break;
+      }
+
       // try to parse number
       if cursor.curr().is_numeric() {
         let number_result = number::Parser::Try_Parse_At(cursor);
@@ -53,7 +58,7 @@ token! {
       match cursor.curr() {
         // end of sentence
         '.' => {
-          if cursor.next_is_ws() {
+          if cursor.next_is_ws() || cursor.is_closer(cursor.next()) {
             cursor.read();
             result.add_child(
               Token::With_Name("period")
@@ -81,15 +86,15 @@ token! {
         }
         // metadata
         '{' => {
-          todo!("metadata")
+          return End::ToDo("metadata");
         }
         // block
         '[' => {
-          todo!("block")
+          return End::ToDo("block");
         }
         // addendum
         '(' => {
-          todo!("addendum")
+          return End::ToDo("addendum");
         }
         // tag
         '#' => {
@@ -101,25 +106,25 @@ token! {
         }
         // italic and bold markup formatting
         '*' => {
-          todo!("italic and bold")
+          return End::ToDo("italic and bold");
         }
         // inline code markup formatting
         '`' => {
-          todo!("inline code")
+          return End::ToDo("inline code");
         }
         // strikethrough markup formatting
         '~' => {
-          todo!("strikethrough")
+          return End::ToDo("strikethrough");
         }
         // emoji
         ':' => {
-          todo!("emoji")
+          return End::ToDo("emoji");
         }
         '=' => {
-          todo!("hilighted text")
+          return End::ToDo("hilighted text");
         }
         '@' => {
-          todo!("ref")
+          return End::ToDo("ref");
         }
         _ => {}
       }
@@ -131,11 +136,45 @@ token! {
           result.add_child(token);
           continue;
         }
-        None => {}
+        // nothing else can be read here, so the sentence ends.
+        None => {
+          break;
+        }
       }
 
     }
 
-    result.start(start).to_end()
-  }
+    if result.children.is_none() {
+      return End::None;
+    }
+
+    // trailing whitespace read before the sentence ended isn't part of it.
+    result.start(start).end(cursor.prev_non_ws_pos()).to_end()
+  },
+  tests:
+    unit!(["Words"]
+      : "a b"
+      => Parsed::Pass(Token::New()
+        .name(&KEY)
+        .child(Token::New()
+          .name(word::KEY)
+          .partial()
+          .build_from(0, 0))
+        .child(Token::New()
+          .name(word::KEY)
+          .partial()
+          .build_from(2, 2))
+        .build_from(0, 2)))
+    unit!(["Unreadable Char"]
+      : ".x"
+      => Parsed::Fail(None))
+    unit!(["Word Before Unreadable Char"]
+      : "a .x"
+      => Parsed::Pass(Token::New()
+        .name(&KEY)
+        .child(Token::New()
+          .name(word::KEY)
+          .partial()
+          .build_from(0, 0))
+        .build_from(0, 0)))
 }
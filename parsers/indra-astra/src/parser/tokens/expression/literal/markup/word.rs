@@ -22,7 +22,7 @@ token! {
       }
 
       let curr = cursor.curr();
-      if curr.is_whitespace() {
+      if curr.is_whitespace() || cursor.curr_is_closer() {
         break;
       } else if curr.is_alphanumeric() {
         cursor.read();
@@ -32,7 +32,7 @@ token! {
           '.' => {
            if cursor.is_at(result.start.unwrap()) {
               return End::None;
-            } else if cursor.next_is_ws() {
+            } else if cursor.next_is_ws() || cursor.is_closer(cursor.next()) {
               break;
             } else if cursor.next_is('.') {
               break;
@@ -68,16 +68,16 @@ token! {
             req_child!([cursor] escape => result);
           }
           '>' => {
-            todo!("input if first letter is >")
+            return End::ToDo("input if first letter is >");
           }
           '{' => {
-            todo!("metadata")
+            return End::ToDo("metadata");
           }
           '[' => {
-            todo!("block")
+            return End::ToDo("block");
           }
           '(' => {
-            todo!("addendum")
+            return End::ToDo("addendum");
           }
           _ => {
             cursor.read();
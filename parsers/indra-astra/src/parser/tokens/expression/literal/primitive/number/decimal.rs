@@ -1,7 +1,73 @@
-use crate::parser::tokens::token;
+use crate::parser::tokens::{expression::literal::primitive::number, token};
 
 token! {
   decimal => |cursor: &mut Cursor| {
-    End::Not_Implemented()
-  }
+    number::read(cursor, &KEY)
+  },
+  tests:
+    unit!(["Fraction"]
+      : "3.14"
+      => Parsed::Pass(Token::New()
+        .name(&KEY)
+        .tag(number::Radix::Decimal.tag())
+        .prop(number::VALUE_KEY, Token::New()
+          .name(number::VALUE_KEY)
+          .build_from(0, 3))
+        .build_from(0, 3)))
+    unit!(["Negative" & "Fraction"]
+      : "-0.5"
+      => Parsed::Pass(Token::New()
+        .name(&KEY)
+        .tag(number::Radix::Decimal.tag())
+        .tag(number::SIGNED_TAG)
+        .prop(number::SIGN_KEY, Token::New()
+          .name(number::SIGN_KEY)
+          .build_from(0, 0))
+        .prop(number::VALUE_KEY, Token::New()
+          .name(number::VALUE_KEY)
+          .build_from(1, 3))
+        .build_from(0, 3)))
+    unit!(["Exponent"]
+      : "6.022e+23"
+      => Parsed::Pass(Token::New()
+        .name(&KEY)
+        .tag(number::Radix::Decimal.tag())
+        .tag(number::SCIENTIFIC_TAG)
+        .prop(number::VALUE_KEY, Token::New()
+          .name(number::VALUE_KEY)
+          .build_from(0, 4))
+        .prop(number::EXPONENT_KEY, Token::New()
+          .name(number::EXPONENT_KEY)
+          .build_from(6, 8))
+        .build_from(0, 8)))
+    unit!(["Exponent" & "Without Fraction"]
+      : "1e10"
+      => Parsed::Pass(Token::New()
+        .name(&KEY)
+        .tag(number::SCIENTIFIC_TAG)
+        .partial()
+        .build_from(0, 3)))
+    unit!(["Separators" & "Fraction"]
+      : "1_000.000_1"
+      => Parsed::Pass(Token::New()
+        .name(&KEY)
+        .partial()
+        .build_from(0, 10)))
+    unit!(["Out Of Range"]
+      : "1e400"
+      => Parsed::Fail(Some(Error::New("invalid-decimal")
+        .tag("invalid")
+        .partial()
+        .build_from(0, 4)
+        .unwrap())))
+    unit!(["Separators" & "Doubled"]
+      : "1.0__5"
+      => Parsed::Fail(Some(Error::New("invalid-decimal")
+        .tag("invalid")
+        .partial()
+        .build_from(0, 5)
+        .unwrap())))
+    unit!(["Separators" & "Doubled" & "Integer"]
+      : "1__0"
+      => Parsed::Fail(None))
 }
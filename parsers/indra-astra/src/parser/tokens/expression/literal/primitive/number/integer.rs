@@ -1,7 +1,75 @@
-use crate::parser::tokens::token;
+use crate::parser::tokens::{expression::literal::primitive::number, token};
 
 token! {
   integer => |cursor: &mut Cursor| {
-    End::Not_Implemented()
-  }
+    number::read(cursor, &KEY)
+  },
+  tests:
+    unit!(["Digits"]
+      : "42"
+      => Parsed::Pass(Token::New()
+        .name(&KEY)
+        .tag(number::Radix::Decimal.tag())
+        .prop(number::VALUE_KEY, Token::New()
+          .name(number::VALUE_KEY)
+          .build_from(0, 1))
+        .build_from(0, 1)))
+    unit!(["Negative"]
+      : "-7"
+      => Parsed::Pass(Token::New()
+        .name(&KEY)
+        .tag(number::Radix::Decimal.tag())
+        .tag(number::SIGNED_TAG)
+        .prop(number::SIGN_KEY, Token::New()
+          .name(number::SIGN_KEY)
+          .build_from(0, 0))
+        .prop(number::VALUE_KEY, Token::New()
+          .name(number::VALUE_KEY)
+          .build_from(1, 1))
+        .build_from(0, 1)))
+    unit!(["Separators"]
+      : "1_000_000"
+      => Parsed::Pass(Token::New()
+        .name(&KEY)
+        .tag(number::Radix::Decimal.tag())
+        .partial()
+        .build_from(0, 8)))
+    unit!(["Hex"]
+      : "0xFF"
+      => Parsed::Pass(Token::New()
+        .name(&KEY)
+        .tag(number::Radix::Hex.tag())
+        .prop(number::VALUE_KEY, Token::New()
+          .name(number::VALUE_KEY)
+          .build_from(2, 3))
+        .build_from(0, 3)))
+    unit!(["Binary" & "Separators"]
+      : "0b1010_0101"
+      => Parsed::Pass(Token::New()
+        .name(&KEY)
+        .tag(number::Radix::Binary.tag())
+        .partial()
+        .build_from(0, 10)))
+    unit!(["Octal" & "Positive"]
+      : "+0o17"
+      => Parsed::Pass(Token::New()
+        .name(&KEY)
+        .tag(number::Radix::Octal.tag())
+        .tag(number::SIGNED_TAG)
+        .partial()
+        .build_from(0, 4)))
+    unit!(["Out Of Range"]
+      : "9_223_372_036_854_775_808"
+      => Parsed::Fail(Some(Error::New("invalid-integer")
+        .tag("invalid")
+        .partial()
+        .build_from(0, 24)
+        .unwrap())))
+    unit!(["Separators" & "Doubled"]
+      : "1__0"
+      => Parsed::Fail(Some(Error::New("invalid-integer")
+        .tag("invalid")
+        .partial()
+        .build_from(0, 3)
+        .unwrap())))
 }
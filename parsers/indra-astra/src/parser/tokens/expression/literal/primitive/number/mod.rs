@@ -1,5 +1,357 @@
-use crate::parser::tokens::splay_mods;
+use crate::parser::{
+    cursor::Cursor,
+    results::{
+        builder::Builder,
+        end::End,
+        token::Token,
+        view::{self, ViewError},
+    },
+    tokens::splay_mods,
+};
 
 splay_mods! {
     number: [decimal, integer]
 }
+
+/// Tags a number written with an explicit `+` or `-` sign.
+pub const SIGNED_TAG: &'static str = "signed";
+/// Tags a decimal written with an exponent; like `6.02e23`.
+pub const SCIENTIFIC_TAG: &'static str = "scientific";
+
+/// The names of the props of a number literal's parts.
+pub const SIGN_KEY: &'static str = "sign";
+pub const VALUE_KEY: &'static str = "value";
+pub const EXPONENT_KEY: &'static str = "exponent";
+
+/// The base a number literal's digits are written in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Radix {
+    Binary,
+    Octal,
+    Decimal,
+    Hex,
+}
+
+impl Radix {
+    /// The radix with the given prefix char; like the `x` in `0xFF`.
+    pub fn of_prefix(c: char) -> Option<Radix> {
+        match c {
+            'b' | 'B' => Some(Radix::Binary),
+            'o' | 'O' => Some(Radix::Octal),
+            'x' | 'X' => Some(Radix::Hex),
+            _ => None,
+        }
+    }
+
+    pub fn base(&self) -> u32 {
+        match self {
+            Radix::Binary => 2,
+            Radix::Octal => 8,
+            Radix::Decimal => 10,
+            Radix::Hex => 16,
+        }
+    }
+
+    /// The tag added to number tokens written in this radix.
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Radix::Binary => "radix_2",
+            Radix::Octal => "radix_8",
+            Radix::Decimal => "radix_10",
+            Radix::Hex => "radix_16",
+        }
+    }
+
+    pub fn is_digit(&self, c: char) -> bool {
+        c.is_digit(self.base())
+    }
+}
+
+/// The value of a number literal.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Value {
+    Integer(i64),
+    Decimal(f64),
+}
+
+/// Reads the value of the text of a number literal; like `-1_000`, `0xFF`, or `2.5e-3`.
+/// - Integers must fit in an `i64`, and decimals must be finite as an `f64`.
+pub fn value_of(literal: &str) -> Result<Value, String> {
+    let (negative, unsigned) = match literal.chars().next() {
+        Some('-') => (true, &literal[1..]),
+        Some('+') => (false, &literal[1..]),
+        _ => (false, literal),
+    };
+
+    let mut chars = unsigned.chars();
+    let radix = match (chars.next(), chars.next().and_then(Radix::of_prefix)) {
+        (Some('0'), Some(radix)) => radix,
+        _ => Radix::Decimal,
+    };
+    let digits: String = match radix {
+        Radix::Decimal => unsigned,
+        _ => &unsigned[2..],
+    }
+    .chars()
+    .filter(|c| *c != '_')
+    .collect();
+
+    if radix == Radix::Decimal && digits.contains(|c| c == '.' || c == 'e' || c == 'E') {
+        return match digits.parse::<f64>() {
+            Ok(value) if value.is_finite() => Ok(Value::Decimal(if negative { -value } else { value })),
+            Ok(_) => Err(format!("The decimal `{}` is too large to be represented.", literal)),
+            Err(_) => Err(format!("The decimal `{}` is not a valid number.", literal)),
+        };
+    }
+
+    let magnitude = i128::from_str_radix(&digits, radix.base())
+        .ok()
+        .filter(|magnitude| *magnitude <= u64::MAX as i128);
+    match magnitude.map(|magnitude| if negative { -magnitude } else { magnitude }) {
+        Some(value) if value >= i64::MIN as i128 && value <= i64::MAX as i128 => Ok(Value::Integer(value as i64)),
+        _ => Err(format!(
+            "The integer `{}` is out of range; integers must be between {} and {}.",
+            literal,
+            i64::MIN,
+            i64::MAX
+        )),
+    }
+}
+
+/// A typed view of an integer or decimal literal; with its value.
+#[derive(Debug, Clone, Copy)]
+pub struct Number<'t> {
+    pub token: &'t Token,
+    pub value: Value,
+}
+
+impl<'t> Number<'t> {
+    /// Views the number token; reading its value from the source it was parsed from.
+    #[allow(non_snake_case)]
+    pub fn Of(token: &'t Token, source: &str) -> Result<Number<'t>, ViewError> {
+        let kind = match view::View::<integer::Parser>::try_from(token) {
+            Ok(_) => integer::KEY,
+            Err(_) => view::View::<decimal::Parser>::try_from(token).map(|_| decimal::KEY)?,
+        };
+
+        let text: String = source.chars().skip(token.start).take(token.end + 1 - token.start).collect();
+        match value_of(&text) {
+            Ok(value) => Ok(Number { token, value }),
+            Err(message) => Err(ViewError::Invalid { token: kind, message }),
+        }
+    }
+}
+
+/// Reads a number literal of the given kind (`integer::KEY` or `decimal::KEY`) at the cursor.
+/// - Doesn't match if the literal is of the other kind, or runs into the letters of a word; like `3rd`.
+pub(super) fn read(cursor: &mut Cursor, kind: &str) -> End {
+    let literal = match _scan(cursor) {
+        Scan::None => return End::None,
+        Scan::Invalid(length, message, is_decimal) => {
+            // the other kind reports it instead.
+            if is_decimal != (kind == decimal::KEY) {
+                return End::None;
+            }
+
+            for _ in 0..length {
+                cursor.read();
+            }
+
+            return End::Invalid(kind, &message);
+        }
+        Scan::Literal(literal) => literal,
+    };
+
+    let is_decimal = literal.fraction || literal.exponent.is_some();
+    if is_decimal != (kind == decimal::KEY) {
+        return End::None;
+    }
+
+    let start = cursor.curr_pos();
+    let text = cursor.slice(start, start + literal.length);
+    if let Err(message) = value_of(&text) {
+        for _ in 0..literal.length {
+            cursor.read();
+        }
+
+        return End::Invalid(kind, &message);
+    }
+
+    let mut result = End::New().tag(literal.radix.tag());
+    if literal.signed {
+        result.add_tag(SIGNED_TAG);
+        result.set_prop(SIGN_KEY, Token::New().name(SIGN_KEY).build_from(start, start));
+    }
+
+    result.set_prop(
+        VALUE_KEY,
+        Token::New()
+            .name(VALUE_KEY)
+            .build_from(start + literal.value.0, start + literal.value.1 - 1),
+    );
+
+    if let Some((from, to)) = literal.exponent {
+        result.add_tag(SCIENTIFIC_TAG);
+        result.set_prop(EXPONENT_KEY, Token::New().name(EXPONENT_KEY).build_from(start + from, start + to - 1));
+    }
+
+    for _ in 0..literal.length {
+        cursor.read();
+    }
+
+    result.to_end()
+}
+
+/// The parts of a number literal; as offsets from its start.
+struct Literal {
+    length: usize,
+    signed: bool,
+    radix: Radix,
+    /// The digits after any sign and prefix; up to any exponent.
+    value: (usize, usize),
+    fraction: bool,
+    /// The exponent's sign and digits; after the `e`.
+    exponent: Option<(usize, usize)>,
+}
+
+enum Scan {
+    None,
+    /// A literal that can't be read; with its length, why, and if it was written as a decimal.
+    Invalid(usize, String, bool),
+    Literal(Literal),
+}
+
+/// Looks ahead of the cursor for a number literal; without moving it.
+fn _scan(cursor: &Cursor) -> Scan {
+    let mut at = 0;
+    let signed = cursor.curr() == '-' || cursor.curr() == '+';
+    if signed {
+        at += 1;
+    }
+
+    let mut radix = Radix::Decimal;
+    if cursor.ahead(at) == '0' {
+        if let Some(prefixed) = Radix::of_prefix(cursor.ahead(at + 1)) {
+            radix = prefixed;
+            at += 2;
+        }
+    }
+
+    let value_start = at;
+    if !radix.is_digit(cursor.ahead(at)) {
+        return Scan::None;
+    }
+
+    at = match _scan_digits(cursor, at, radix) {
+        Ok(end) => end,
+        Err(end) => return _separator_error(cursor, end, radix),
+    };
+
+    let mut fraction = false;
+    let mut exponent = None;
+    if radix == Radix::Decimal && cursor.ahead(at) == '.' && cursor.ahead(at + 1).is_ascii_digit() {
+        fraction = true;
+        at = match _scan_digits(cursor, at + 1, radix) {
+            Ok(end) => end,
+            Err(end) => return _separator_error(cursor, end, radix),
+        };
+    }
+
+    let value_end = at;
+    if radix == Radix::Decimal && (cursor.ahead(at) == 'e' || cursor.ahead(at) == 'E') {
+        let exponent_start = at + 1;
+        let digits_start = match cursor.ahead(exponent_start) {
+            '+' | '-' => exponent_start + 1,
+            _ => exponent_start,
+        };
+
+        if cursor.ahead(digits_start).is_ascii_digit() {
+            at = match _scan_digits(cursor, digits_start, radix) {
+                Ok(end) => end,
+                Err(end) => return _separator_error(cursor, end, radix),
+            };
+            exponent = Some((exponent_start, at));
+        }
+    }
+
+    if !_is_boundary(cursor, at) {
+        return Scan::None;
+    }
+
+    Scan::Literal(Literal {
+        length: at,
+        signed,
+        radix,
+        value: (value_start, value_end),
+        fraction,
+        exponent,
+    })
+}
+
+/// Scans a run of digits and `_` separators starting at the offset; returning where it ends.
+/// - Fails with the offset of the separator if one isn't between two digits.
+fn _scan_digits(cursor: &Cursor, from: usize, radix: Radix) -> Result<usize, usize> {
+    let mut at = from;
+    loop {
+        let curr = cursor.ahead(at);
+        if radix.is_digit(curr) {
+            at += 1;
+        } else if curr == '_' {
+            if at == from || !radix.is_digit(cursor.ahead(at + 1)) {
+                return Err(at);
+            }
+            at += 1;
+        } else {
+            return Ok(at);
+        }
+    }
+}
+
+fn _separator_error(cursor: &Cursor, at: usize, radix: Radix) -> Scan {
+    let mut length = at + 1;
+    while cursor.ahead(length).is_alphanumeric() || cursor.ahead(length) == '_' {
+        length += 1;
+    }
+
+    let text = cursor.slice(cursor.curr_pos(), cursor.curr_pos() + length);
+    let is_decimal = radix == Radix::Decimal
+        && (text.contains(|c| c == '.' || c == 'e' || c == 'E')
+            || (cursor.ahead(length) == '.' && cursor.ahead(length + 1).is_ascii_digit()));
+
+    Scan::Invalid(
+        length,
+        format!("A digit separator must be between two digits, but found: `{}`.", text),
+        is_decimal,
+    )
+}
+
+/// If a number can end before the given offset; which it can't if the next char would continue a word.
+fn _is_boundary(cursor: &Cursor, at: usize) -> bool {
+    match cursor.ahead(at) {
+        '.' => !cursor.ahead(at + 1).is_alphanumeric(),
+        c => !(c.is_alphanumeric() || c == '_'),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _number(kind: &str, source: &str) -> Result<Value, ViewError> {
+        let token = Token::New().name(kind).tag(kind).build_from(0, source.chars().count() - 1);
+        Number::Of(&token, source).map(|number| number.value)
+    }
+
+    #[test]
+    fn views_have_the_literal_value() {
+        assert_eq!(_number(integer::KEY, "42"), Ok(Value::Integer(42)));
+        assert_eq!(_number(integer::KEY, "-0x1_F"), Ok(Value::Integer(-31)));
+        assert_eq!(_number(decimal::KEY, "2.5e-3"), Ok(Value::Decimal(0.0025)));
+    }
+
+    #[test]
+    fn views_of_other_tokens_mismatch() {
+        let token = Token::New().name("word").build_from(0, 0);
+        assert!(matches!(Number::Of(&token, "a"), Err(ViewError::Mismatch { .. })));
+    }
+}
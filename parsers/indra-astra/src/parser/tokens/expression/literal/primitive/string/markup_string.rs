@@ -0,0 +1,218 @@
+use crate::parser::{
+    cursor::Cursor,
+    results::{builder::Builder, end::End, error::Error, parsed::Parsed, token::Token, token_builder::TokenBuilder},
+    tokens::{
+        attribute,
+        expression::{
+            literal::{
+                escape::quote_escape,
+                markup::{sentence, word},
+                primitive::string,
+            },
+            value_expression,
+        },
+        token,
+    },
+    Parser as _,
+};
+
+pub const DELIMITER: char = '"';
+pub const UNTERMINATED_KEY: &'static str = "unterminated_markup_string";
+pub const UNCLOSED_INTERPOLATION_KEY: &'static str = "unclosed_interpolation";
+/// The name of the tokens for `{expression}` segments; with the expression as their `value` prop.
+pub const INTERPOLATION_KEY: &'static str = "interpolation";
+
+/// Tags a markup string with `{expression}` segments.
+pub const INTERPOLATED_TAG: &'static str = "interpolated";
+
+const BODY_CLOSERS: &'static [char] = &[DELIMITER, '{'];
+const INTERPOLATION_CLOSERS: &'static [char] = &['}', DELIMITER];
+
+token! {
+    markup_string => |cursor: &mut Cursor| {
+        let start = cursor.curr_pos();
+        if !cursor.try_read(DELIMITER) {
+            return End::Missing("start-delimiter", "\"", &cursor.curr_str());
+        }
+
+        let mut result = Token::Of_Type::<Parser>();
+        cursor.open_delimited(BODY_CLOSERS);
+        let end = _read_body(cursor, &mut result, start);
+        cursor.close_delimited();
+
+        end.unwrap_or_else(|| result.to_end())
+    },
+    tests:
+        unit!(["Words"]
+            : "\"hello world\""
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .child(Token::New()
+                    .name(sentence::KEY)
+                    .child(Token::New()
+                        .name(word::KEY)
+                        .partial()
+                        .build_from(1, 5))
+                    .child(Token::New()
+                        .name(word::KEY)
+                        .partial()
+                        .build_from(7, 11))
+                    .build_from(1, 11))
+                .build_from(0, 12)))
+        unit!(["Empty"]
+            : "\"\""
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .build_from(0, 1)))
+        unit!(["Tag"]
+            : "\"a #b\""
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .child(Token::New()
+                    .name(sentence::KEY)
+                    .child(Token::New()
+                        .name(word::KEY)
+                        .partial()
+                        .build_from(1, 1))
+                    .child(Token::New()
+                        .name(attribute::tag::KEY)
+                        .partial()
+                        .build_from(3, 4))
+                    .build_from(1, 4))
+                .build_from(0, 5)))
+        unit!(["Escaped Quote"]
+            : "\"\\\"\""
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .child(Token::New()
+                    .name(sentence::KEY)
+                    .child(Token::New()
+                        .name(quote_escape::double::KEY)
+                        .partial()
+                        .build_from(1, 2))
+                    .build_from(1, 2))
+                .build_from(0, 3)))
+        unit!(["Interpolation"]
+            : "\"hi {name}!\""
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .tag(INTERPOLATED_TAG)
+                .child(Token::New()
+                    .name(sentence::KEY)
+                    .partial()
+                    .build_from(1, 2))
+                .child(Token::New()
+                    .name(INTERPOLATION_KEY)
+                    .prop("value", Token::New()
+                        .name(word::KEY)
+                        .partial()
+                        .build_from(5, 8))
+                    .build_from(4, 9))
+                .child(Token::New()
+                    .name(sentence::KEY)
+                    .partial()
+                    .build_from(10, 10))
+                .build_from(0, 11)))
+        unit!(["Unterminated"]
+            : "\"abc\nxyz\""
+            => Parsed::Fail(Some(Error::New(UNTERMINATED_KEY)
+                .tag("unterminated")
+                .partial()
+                .build_from(0, 3)
+                .unwrap())))
+        unit!(["Interpolation" & "Unclosed"]
+            : "\"a {b c\""
+            => Parsed::Fail(Some(Error::New(UNCLOSED_INTERPOLATION_KEY)
+                .tag("unterminated")
+                .partial()
+                .build_from(3, 6)
+                .unwrap())))
+}
+
+/// Reads the segments of the string after its opening quote; returning how it ended if it couldn't be read.
+fn _read_body(cursor: &mut Cursor, result: &mut TokenBuilder, start: usize) -> Option<End> {
+    loop {
+        while cursor.curr_is(' ') || cursor.curr_is('\t') {
+            cursor.read();
+        }
+
+        if cursor.is_eof() || cursor.curr_is('\n') {
+            return Some(_unterminated(cursor, start));
+        }
+
+        match cursor.curr() {
+            DELIMITER => {
+                cursor.read();
+                return None;
+            }
+            '{' => match _read_interpolation(cursor) {
+                Ok(interpolation) => {
+                    result.add_child(interpolation);
+                    result.add_tag(INTERPOLATED_TAG);
+                }
+                Err(end) => return Some(end),
+            },
+            _ => match sentence::Parser::Parse_Opt_At(cursor) {
+                Parsed::Pass(sentence) => {
+                    result.add_child(sentence);
+                }
+                Parsed::Fail(Some(error)) => return Some(End::Error_In_Child(KEY, error)),
+                Parsed::Fail(None) => return Some(End::Unexpected("char-in-markup-string", &cursor.curr_str())),
+            },
+        }
+    }
+}
+
+/// Reads an `{expression}` segment.
+fn _read_interpolation(cursor: &mut Cursor) -> Result<Token, End> {
+    let start = cursor.curr_pos();
+    cursor.read();
+
+    cursor.open_delimited(INTERPOLATION_CLOSERS);
+    cursor.skip_spacing();
+    let value = value_expression::Parser::Parse_Opt_At(cursor);
+    cursor.skip_spacing();
+    cursor.close_delimited();
+
+    let value = match value {
+        Parsed::Pass(value) => value,
+        Parsed::Fail(error) => {
+            return Err(End::Error_In_Prop_Of(
+                Token::With_Name(INTERPOLATION_KEY),
+                "value",
+                error,
+            ))
+        }
+    };
+
+    if !cursor.try_read('}') {
+        while !cursor.is_eof() && !cursor.curr_is('\n') && !cursor.curr_is(DELIMITER) {
+            cursor.read();
+        }
+
+        return Err(Error::New(UNCLOSED_INTERPOLATION_KEY)
+            .text(&format!("Expected `}}` to close the interpolation, but found: `{}`.", cursor.curr_str()))
+            .tag("unterminated")
+            .start(start)
+            .end(cursor.prev_pos())
+            .to_end());
+    }
+
+    Ok(Token::With_Name(INTERPOLATION_KEY)
+        .prop("value", value)
+        .build_from(start, cursor.prev_pos()))
+}
+
+/// The error for a string without a closing quote; spanning from its opening quote to the end of its line.
+fn _unterminated(cursor: &mut Cursor, start: usize) -> End {
+    while !cursor.is_eof() && !cursor.curr_is('\n') {
+        cursor.read();
+    }
+
+    Error::New(UNTERMINATED_KEY)
+        .text("Expected a closing `\"` for the string, but found the end of the line.")
+        .tag("unterminated")
+        .start(start)
+        .end(cursor.prev_pos())
+        .to_end()
+}
@@ -1,5 +1,10 @@
 use crate::parser::tokens::splay_mods;
 
 splay_mods! {
-    string: [simple_string]
+    string: [simple_string, markup_string]
 }
+
+/// Tags a string without any escapes; whose text is exactly its value. (And each segment of raw text in a string)
+pub const RAW_TAG: &'static str = "raw";
+/// Tags a string with escapes; as children in between its segments of raw text. (And each of those escapes)
+pub const ESCAPED_TAG: &'static str = "escaped";
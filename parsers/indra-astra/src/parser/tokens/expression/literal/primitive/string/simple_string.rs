@@ -1,5 +1,210 @@
-use crate::parser::tokens::token;
+use crate::parser::{
+    cursor::Cursor,
+    results::{builder::Builder, end::End, error::Error, token::Token, token_builder::TokenBuilder},
+    tokens::{
+        expression::literal::{
+            escape::{self, quote_escape, tab_escape},
+            primitive::string,
+        },
+        token,
+    },
+};
+
+pub const DELIMITER: char = '\'';
+pub const UNTERMINATED_KEY: &'static str = "unterminated_simple_string";
+/// The name of the tokens for the runs of raw text between a string's escapes.
+pub const SEGMENT_KEY: &'static str = "segment";
 
 token! {
-    simple_string => |cursor: &mut Cursor| End::Missing("start-delimiter", "\'", &cursor.curr_str())
+    simple_string => |cursor: &mut Cursor| {
+        let start = cursor.curr_pos();
+        if !cursor.try_read(DELIMITER) {
+            return End::Missing("start-delimiter", "\'", &cursor.curr_str());
+        }
+
+        let mut result = Token::Of_Type::<Parser>();
+        let mut is_escaped = false;
+        let mut segment = None;
+        loop {
+            match cursor.curr() {
+                DELIMITER => {
+                    _end_segment(cursor, &mut result, &mut segment);
+                    cursor.read();
+                    break;
+                }
+                // an escape at the end of the line can't continue the string onto the next one.
+                '\\' if cursor.next() != '\n' && !cursor.eof_at(cursor.next_pos()) => {
+                    _end_segment(cursor, &mut result, &mut segment);
+                    match escape::Parser::Parse_At(cursor) {
+                        Parsed::Pass(escape) => {
+                            result.add_child(escape.as_builder().tag(string::ESCAPED_TAG).build());
+                            is_escaped = true;
+                        }
+                        Parsed::Fail(error) => return End::Error_In_Child_Of(result, error),
+                    }
+                }
+                _ if cursor.is_eof() || cursor.curr_is('\n') => {
+                    return _unterminated(cursor, start);
+                }
+                _ => {
+                    segment.get_or_insert(cursor.curr_pos());
+                    cursor.read();
+                }
+            }
+        }
+
+        if is_escaped {
+            result.tag(string::ESCAPED_TAG).to_end()
+        } else {
+            result.tag(string::RAW_TAG).to_end()
+        }
+    },
+    tests:
+        unit!(["Raw"]
+            : "'abc'"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .tag(string::RAW_TAG)
+                .child(Token::New()
+                    .name(SEGMENT_KEY)
+                    .tag(string::RAW_TAG)
+                    .build_from(1, 3))
+                .build_from(0, 4)))
+        unit!(["Raw" & "Empty"]
+            : "''"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .tag(string::RAW_TAG)
+                .build_from(0, 1)))
+        unit!(["Raw" & "Double Quotes"]
+            : "'say \"hi\"'"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .tag(string::RAW_TAG)
+                .child(Token::New()
+                    .name(SEGMENT_KEY)
+                    .tag(string::RAW_TAG)
+                    .build_from(1, 8))
+                .build_from(0, 9)))
+        unit!(["Escaped" & "Quote"]
+            : "'it\\'s'"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .tag(string::ESCAPED_TAG)
+                .child(Token::New()
+                    .name(SEGMENT_KEY)
+                    .tag(string::RAW_TAG)
+                    .build_from(1, 2))
+                .child(Token::New()
+                    .name(quote_escape::single::KEY)
+                    .tag(string::ESCAPED_TAG)
+                    .partial()
+                    .build_from(3, 4))
+                .child(Token::New()
+                    .name(SEGMENT_KEY)
+                    .tag(string::RAW_TAG)
+                    .build_from(5, 5))
+                .build_from(0, 6)))
+        unit!(["Escaped" & "Only"]
+            : "'\\t'"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .tag(string::ESCAPED_TAG)
+                .child(Token::New()
+                    .name(tab_escape::KEY)
+                    .tag(string::ESCAPED_TAG)
+                    .partial()
+                    .build_from(1, 2))
+                .build_from(0, 3)))
+        unit!(["Escaped" & "Tab" & "Unknown"]
+            : "'a\\tb\\qc'"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .tag(string::ESCAPED_TAG)
+                .child(Token::New()
+                    .name(SEGMENT_KEY)
+                    .tag(string::RAW_TAG)
+                    .build_from(1, 1))
+                .child(Token::New()
+                    .name(tab_escape::KEY)
+                    .tag(string::ESCAPED_TAG)
+                    .partial()
+                    .build_from(2, 3))
+                .child(Token::New()
+                    .name(SEGMENT_KEY)
+                    .tag(string::RAW_TAG)
+                    .build_from(4, 4))
+                .child(Token::New()
+                    .name(escape::escape_sequence::KEY)
+                    .tag(string::ESCAPED_TAG)
+                    .partial()
+                    .build_from(5, 6))
+                .child(Token::New()
+                    .name(SEGMENT_KEY)
+                    .tag(string::RAW_TAG)
+                    .build_from(7, 7))
+                .build_from(0, 8)))
+        unit!(["Mixed"]
+            : "'\\ta b\\'c'"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .tag(string::ESCAPED_TAG)
+                .child(Token::New()
+                    .name(tab_escape::KEY)
+                    .tag(string::ESCAPED_TAG)
+                    .partial()
+                    .build_from(1, 2))
+                .child(Token::New()
+                    .name(SEGMENT_KEY)
+                    .tag(string::RAW_TAG)
+                    .build_from(3, 5))
+                .child(Token::New()
+                    .name(quote_escape::single::KEY)
+                    .tag(string::ESCAPED_TAG)
+                    .partial()
+                    .build_from(6, 7))
+                .child(Token::New()
+                    .name(SEGMENT_KEY)
+                    .tag(string::RAW_TAG)
+                    .build_from(8, 8))
+                .build_from(0, 9)))
+        unit!(["Unterminated"]
+            : "'abc\nxyz'"
+            => Parsed::Fail(Some(Error::New(UNTERMINATED_KEY)
+                .tag("unterminated")
+                .partial()
+                .build_from(0, 3)
+                .unwrap())))
+        unit!(["Unterminated" & "Escaped Quote"]
+            : "'abc\\'"
+            => Parsed::Fail(Some(Error::New(UNTERMINATED_KEY)
+                .tag("unterminated")
+                .partial()
+                .build_from(0, 5)
+                .unwrap())))
+}
+
+/// Adds the segment of raw text being read as a child; if there is one.
+fn _end_segment(cursor: &Cursor, result: &mut TokenBuilder, segment: &mut Option<usize>) {
+    if let Some(start) = segment.take() {
+        result.add_child(
+            Token::With_Name(SEGMENT_KEY)
+                .tag(string::RAW_TAG)
+                .build_from(start, cursor.prev_pos()),
+        );
+    }
+}
+
+/// The error for a string without a closing quote; spanning from its opening quote to the end of its line.
+fn _unterminated(cursor: &mut Cursor, start: usize) -> End {
+    while !cursor.is_eof() && !cursor.curr_is('\n') {
+        cursor.read();
+    }
+
+    Error::New(UNTERMINATED_KEY)
+        .text("Expected a closing `'` for the string, but found the end of the line.")
+        .tag("unterminated")
+        .start(start)
+        .end(cursor.prev_pos())
+        .to_end()
 }
@@ -0,0 +1,34 @@
+use crate::parser::tokens::{
+    expression::literal::{markup::word, primitive::number::integer, structure::closure},
+    token,
+};
+
+pub const OPENER: char = '[';
+pub const CLOSER: char = ']';
+
+token! {
+    block => |cursor: &mut Cursor| {
+        closure::read(cursor, &KEY, OPENER, CLOSER)
+    },
+    tests:
+        unit!(["Newline Separated"]
+            : "[\n\t1\n  b\n]"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .child(Token::New()
+                    .name(integer::KEY)
+                    .partial()
+                    .build_from(3, 3))
+                .child(Token::New()
+                    .name(word::KEY)
+                    .partial()
+                    .build_from(7, 7))
+                .build_from(0, 9)))
+        unit!(["Mismatched"]
+            : "[a)"
+            => Parsed::Fail(Some(Error::New("unclosed_block")
+                .tag("unterminated")
+                .partial()
+                .build_from(0, 0)
+                .unwrap())))
+}
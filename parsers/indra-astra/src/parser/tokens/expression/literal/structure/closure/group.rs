@@ -0,0 +1,72 @@
+use crate::parser::{
+    limits::{self, Limits},
+    tokens::{
+        expression::literal::{markup::word, primitive::number::integer, structure::closure},
+        token,
+    },
+};
+
+pub const OPENER: char = '(';
+pub const CLOSER: char = ')';
+
+token! {
+    group => |cursor: &mut Cursor| {
+        closure::read(cursor, &KEY, OPENER, CLOSER)
+    },
+    tests:
+        unit!(["Empty"]
+            : "()"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .build_from(0, 1)))
+        unit!(["Comma Separated"]
+            : "(1, b)"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .child(Token::New()
+                    .name(integer::KEY)
+                    .partial()
+                    .build_from(1, 1))
+                .child(Token::New()
+                    .name(word::KEY)
+                    .partial()
+                    .build_from(4, 4))
+                .build_from(0, 5)))
+        unit!(["Nested"]
+            : "((a))"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .child(Token::New()
+                    .name(&KEY)
+                    .partial()
+                    .build_from(1, 3))
+                .build_from(0, 4)))
+        unit!(["Unclosed"]
+            : "(a, b"
+            => Parsed::Fail(Some(Error::New("unclosed_group")
+                .tag("unterminated")
+                .partial()
+                .build_from(0, 0)
+                .unwrap())))
+        unit!(["Nested" & "Within Depth Limit"]
+            : "((a))"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .partial()
+                .build_from(0, 4)),
+            limits: Limits::Default().depth(12))
+        unit!(["Nested" & "Too Deep"]
+            : "((((a))))"
+            => Parsed::Fail(Some(Error::New(limits::DEPTH_KEY)
+                .tag("limit")
+                .build_from(3, 3)
+                .unwrap())),
+            limits: Limits::Default().depth(12))
+        unit!(["Out Of Fuel"]
+            : "(1, b)"
+            => Parsed::Fail(Some(Error::New(limits::FUEL_KEY)
+                .tag("limit")
+                .build_from(1, 1)
+                .unwrap())),
+            limits: Limits::Default().fuel(3))
+}
@@ -0,0 +1,36 @@
+use crate::parser::tokens::{
+    expression::{assignment::entry::named, literal::structure::closure},
+    token,
+};
+
+pub const OPENER: char = '{';
+pub const CLOSER: char = '}';
+
+token! {
+    map => |cursor: &mut Cursor| {
+        closure::read(cursor, &KEY, OPENER, CLOSER)
+    },
+    tests:
+        unit!(["Entries"]
+            : "{a: 1, b: 2}"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .child(Token::New()
+                    .name(named::KEY)
+                    .partial()
+                    .build_from(1, 4))
+                .child(Token::New()
+                    .name(named::KEY)
+                    .partial()
+                    .build_from(7, 10))
+                .build_from(0, 11)))
+        unit!(["Entry With Word"]
+            : "{a: b}"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .child(Token::New()
+                    .name(named::KEY)
+                    .partial()
+                    .build_from(1, 4))
+                .build_from(0, 5)))
+}
@@ -1,5 +1,131 @@
-use crate::parser::tokens::splay;
+use crate::parser::{
+    cursor::Cursor,
+    farthest::Expectation,
+    results::{builder::Builder, end::End, error::Error, parsed::Parsed, token_builder::TokenBuilder},
+    tokens::{expression::value_expression, token},
+    Parser as _,
+};
 
-splay! {
-    closure: []
+pub mod block;
+pub mod group;
+pub mod map;
+
+token! {
+    closure => |cursor: &mut Cursor| {
+        match cursor.curr() {
+            group::OPENER => End::As::<group::Parser>(&KEY, cursor),
+            block::OPENER => End::As::<block::Parser>(&KEY, cursor),
+            map::OPENER => End::As::<map::Parser>(&KEY, cursor),
+            _ => End::None,
+        }
+    },
+    subs: [group, block, map],
+    variants: [group, block, map]
+}
+
+/// If the char opens a closure.
+pub fn is_opener(c: char) -> bool {
+    c == group::OPENER || c == block::OPENER || c == map::OPENER
+}
+
+/// The chars that end a closure's entries; so words and sentences stop before them.
+const CLOSERS: &'static [char] = &[group::CLOSER, block::CLOSER, map::CLOSER, SEPARATOR];
+
+/// The prefix of the names of the errors for closures without their closer; like `unclosed_group`.
+pub const UNCLOSED_PREFIX: &'static str = "unclosed_";
+
+/// Separates the entries of a closure on the same line; like `(a, b)`.
+pub const SEPARATOR: char = ',';
+
+/// Reads a closure of the given kind (`group::KEY`, `block::KEY` or `map::KEY`) at the cursor.
+/// - Its entries are separated by commas or newlines, and can be indented freely.
+pub(super) fn read(cursor: &mut Cursor, kind: &str, opener: char, closer: char) -> End {
+    let start = cursor.curr_pos();
+    if !cursor.try_read(opener) {
+        return End::Missing("start-delimiter", &opener.to_string(), &cursor.curr_str());
+    }
+
+    let mut result = End::New();
+    cursor.open_delimited(CLOSERS);
+    let end = _read_entries(cursor, &mut result, kind, start, opener, closer);
+    cursor.close_delimited();
+
+    end.unwrap_or_else(|| result.to_end())
+}
+
+/// Reads the entries of a closure after its opener; returning how it ended if it couldn't be read.
+fn _read_entries(
+    cursor: &mut Cursor,
+    result: &mut TokenBuilder,
+    kind: &str,
+    start: usize,
+    opener: char,
+    closer: char,
+) -> Option<End> {
+    loop {
+        cursor.skip_ws();
+        if cursor.is_eof() {
+            return Some(_unclosed(cursor, kind, start, opener, closer));
+        }
+
+        match cursor.curr() {
+            c if c == closer => {
+                cursor.read();
+                return None;
+            }
+            SEPARATOR => return Some(End::Unexpected(&format!("separator-in-{}", kind), &cursor.curr_str())),
+            c if CLOSERS.contains(&c) => return Some(_unclosed(cursor, kind, start, opener, closer)),
+            _ => match value_expression::Parser::Parse_Opt_At(cursor) {
+                Parsed::Pass(entry) => {
+                    result.add_child(entry);
+                }
+                Parsed::Fail(Some(error)) => return Some(End::Error_In_Child(kind, error)),
+                Parsed::Fail(None) => return Some(End::Unexpected(&format!("char-in-{}", kind), &cursor.curr_str())),
+            },
+        }
+
+        cursor.skip_spacing();
+        cursor.try_read(SEPARATOR);
+    }
+}
+
+/// The error for a closure without its closer; pointing at its opener.
+fn _unclosed(cursor: &mut Cursor, kind: &str, start: usize, opener: char, closer: char) -> End {
+    cursor.expect(
+        cursor.curr_pos(),
+        Expectation::Closer {
+            opener,
+            closer,
+            at: start,
+        },
+    );
+
+    Error::New(&format!("{}{}", UNCLOSED_PREFIX, kind))
+        .text(&format!(
+            "Expected a closing `{}` for the {}, but found {}.",
+            closer,
+            kind,
+            if cursor.is_eof() { "the end of the input".to_string() } else { format!("`{}`", cursor.curr_str()) }
+        ))
+        .tag("unterminated")
+        .start(start)
+        .end(start)
+        .to_end()
+}
+
+/// Finds an unclosed closure error within the given error; if it was caused by one.
+#[allow(non_snake_case)]
+pub fn Find_Unclosed(error: &Error) -> Option<&Error> {
+    let is_unclosed = error
+        .name
+        .strip_prefix(UNCLOSED_PREFIX)
+        .is_some_and(|kind| kind == group::KEY || kind == block::KEY || kind == map::KEY);
+    if is_unclosed {
+        return Some(error);
+    }
+
+    error.children.iter().find_map(|child| match child {
+        Parsed::Fail(Some(child)) => Find_Unclosed(child),
+        _ => None,
+    })
 }
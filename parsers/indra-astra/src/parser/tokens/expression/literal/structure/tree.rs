@@ -1,5 +1,6 @@
+use crate::parser::results::{token::Token, view::ViewError};
 use crate::parser::tokens::{
-    statement::branch,
+    statement::branch::{self, Branch},
     token,
     whitespace::indent::{self, Indents},
 };
@@ -18,48 +19,94 @@ token! {
                 cursor.restore();
                 return End::None;
             }
+            Indents::Error(err) => {
+                cursor.pop();
+                return End::Error_In_Child_Of(result, Some(err));
+            }
             _ => {
                 cursor.pop();
             }
         };
 
-        loop {
-            match branch::Parser::Parse_At(cursor) {
-                Parsed::Pass(token) => {
+        let branch_indent = cursor.curr_indent();
+        'branches: loop {
+            let child = match branch::Parser::Parse_At(cursor) {
+                Parsed::Pass(token) => Some(token),
+                Parsed::Fail(Some(error)) if cursor.is_recovering() => {
+                    Some(cursor.recover(error, branch_indent))
+                },
+                Parsed::Fail(error) => match error {
+                    Some(error) => return End::Error_In_Child_Of(result, Some(error)),
+                    None => None,
+                },
+            };
+
+            match child {
+                Some(token) => {
                     result.add_child(token);
-                    cursor.save();
-                    match indent::Parse_Opt_At(cursor) {
-                        Indents::Current(token) => {
+                }
+                None => {
+                    break;
+                },
+            }
+
+            loop {
+                cursor.save();
+                match indent::Parse_Opt_At(cursor) {
+                    Indents::Current(token) => {
+                        cursor.pop();
+                        result.add_child(token);
+                        break;
+                    }
+                    Indents::Decrease(token) => {
+                        if cursor.curr_indent() < initial_indent {
+                            cursor.restore();
+                            break 'branches;
+                        } else {
                             cursor.pop();
                             result.add_child(token);
-                        }
-                        Indents::Decrease(token) => {
-                            if cursor.curr_indent() < initial_indent {
-                                cursor.restore();
-                                break;
-                            } else {
-                                result.add_child(token);
-                            }
-                        }
-                        Indents::Increase(_) => {
-                            panic!("Unexpected increase in indentation after branch");
-                        }
-                        Indents::Error(err) => {
-                            cursor.pop();
-                            return End::Error_In_Child_Of(result, Some(err));
-                        },
-                        _ => {
-                            cursor.pop();
                             break;
                         }
-                    };
-                }
-                Parsed::Fail(error) => match error {
-                    Some(error) => return End::Error_In_Child_Of(result, Some(error)),
-                    None => {
-                        break;
+                    }
+                    Indents::Increase(token) => {
+                        cursor.pop();
+                        let error = Error::New(&format!("unexpected_indent_increase_in_{}", KEY))
+                            .text("Unexpected increase in indentation after branch.")
+                            .tag("unexpected")
+                            .tag("indentation")
+                            .build_from(token.start, token.end)
+                            .unwrap();
+
+                        if cursor.is_recovering() {
+                            result.add_child(cursor.recover(error, branch_indent));
+                            continue;
+                        }
+
+                        return End::Error_In_Child_Of(result, Some(error));
+                    }
+                    Indents::Error(err) => {
+                        cursor.pop();
+                        return End::Error_In_Child_Of(result, Some(err));
                     },
-                },
+                    _ => {
+                        cursor.pop();
+                        if cursor.is_recovering() && !cursor.is_eof() {
+                            // the branch ended before the end of its line.
+                            let start = cursor.curr_pos();
+                            cursor.skip_until(|c| c == '\n');
+                            let error = Error::New(&format!("unexpected_trailing_content_in_{}", KEY))
+                                .text(&format!("Unexpected: `{}`.", cursor.slice(start, cursor.curr_pos()).trim()))
+                                .tag("unexpected")
+                                .build_from(start, cursor.prev_non_ws_pos().max(start))
+                                .unwrap();
+
+                            result.add_child(cursor.recover(error, branch_indent));
+                            continue;
+                        }
+
+                        break 'branches;
+                    }
+                };
             }
 
             if cursor.is_eof() {
@@ -75,12 +122,60 @@ token! {
                     return End::None;
                 }
 
-                return End::Match(result.end(cursor.prev_non_ws_pos()));
+                // a recovered error at the end of whitespace-only input comes after the last non-whitespace char.
+                let end = cursor.prev_non_ws_pos().max(children[children.len() - 1].end);
+                return End::Match(result.end(end));
             }
             None => {
                 return End::None;
             }
         }
 
+    },
+    tests:
+        unit!(["Round Trip"]
+            : "a = 1\nb:\n\tc: 2\n"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .partial()
+                .build_from(0, 14)),
+            lossless: true)
+        unit!(["Round Trip" & "List"]
+            : "- x\n- 1.5 "
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .partial()
+                .build_from(0, 8)),
+            lossless: true)
+        unit!(["Round Trip" & "Trailing Spaces" & "Blank Lines"]
+            : "a: 1  \n\n\nb: 2\t\n\n"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .partial()
+                .build_from(0, 15)),
+            lossless: true)
+}
+
+/// A typed view of an indented tree of branches.
+#[derive(Debug, Clone)]
+pub struct Tree<'t> {
+    pub token: &'t Token,
+    pub branches: Vec<Branch<'t>>,
+}
+
+impl<'t> TryFrom<&'t Token> for Tree<'t> {
+    type Error = ViewError;
+
+    fn try_from(token: &'t Token) -> Result<Self, Self::Error> {
+        let view = View::try_from(token)?;
+        Ok(Tree {
+            token,
+            branches: view
+                .children()
+                .iter()
+                .filter(|child| child.name == branch::KEY)
+                .map(Branch::try_from)
+                .collect::<Result<_, _>>()?,
+        })
     }
 }
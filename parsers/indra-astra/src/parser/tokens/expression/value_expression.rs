@@ -1,13 +1,14 @@
 use crate::{
     parser::{
         cursor::Cursor,
-        results::token::Token,
+        results::{error::Error, parsed::Parsed, token::Token},
         tokens::{
             expression::{
                 assignment, attribute_expression, invocation,
                 literal::{
                     markup::{paragraph, sentence, word},
                     primitive,
+                    structure::closure,
                 },
             },
             token,
@@ -26,8 +27,10 @@ token! {
     value_expression => |cursor: &mut Cursor| {
         cursor.save();
         if let Some(assignment) = assignment::Parser::Try_Parse_At(cursor) {
-            if assignment.prop("operator").is_some() {
+            // ordered entries are marked by their dash instead of an operator.
+            if assignment.prop("operator").is_some() || assignment.tag(assignment::entry::ordered::KEY) {
                 log::info!(&["SUB-ASSIGNMENT"], &format!("{:?}",assignment.tags()));
+                cursor.pop();
                 return End::As_Variant(KEY, Parsed::Pass(assignment));
             } else {
                 log::info!(&["IGNORED".effect(Effect::Strikethrough).color(Color::BrightBlack).as_str()], &format!("@ {}", cursor.curr_pos()));
@@ -39,8 +42,9 @@ token! {
 
         cursor.skip_spacing();
         let first_element = match _try_to_read_value_expression_element(cursor) {
-            Some(token) => token,
-            None => return End::None,
+            Ok(Some(token)) => token,
+            Ok(None) => return End::None,
+            Err(error) => return End::Error_In_Child(KEY, error),
         };
         let mut indent_increase = None;
 
@@ -51,6 +55,14 @@ token! {
                 return End::As_Variant(KEY, Parsed::Pass(first_element))
             },
             indent::Indents::Increase(ident) => {
+                // nested ordered entries belong to the branch's tree instead.
+                // - the cursor stays after the indent; so the branch sees the increase and reads the tree from the dash.
+                //   (restoring it would leave the branch at its own indent; so the nested entries couldn't be read as its tree)
+                if assignment::entry::ordered::is_dash_at(cursor) {
+                    cursor.pop();
+                    return End::As_Variant(KEY, Parsed::Pass(first_element))
+                }
+
                 indent_increase = Some(ident);
             },
             indent::Indents::Decrease(_) => {
@@ -67,7 +79,16 @@ token! {
             },
         }
 
-        let second_element = _try_to_read_value_expression_element(cursor);
+        let second_element = match _try_to_read_value_expression_element(cursor) {
+            Ok(element) => element,
+            Err(error) => {
+                if indent_increase.is_some() {
+                    cursor.pop();
+                }
+
+                return End::Error_In_Child(KEY, error);
+            }
+        };
 
         match second_element {
           None => {
@@ -113,15 +134,23 @@ token! {
     }
 }
 
-pub fn _try_to_read_value_expression_element(cursor: &mut Cursor) -> Option<Token> {
-    match invocation::prefixed::Parser::Try_Parse_At(cursor) {
-        None => match primitive::Parser::Try_Parse_At(cursor) {
-            None => match word::Parser::Try_Parse_At(cursor) {
-                None => None,
-                Some(token) => Some(token),
-            },
-            Some(token) => Some(token),
-        },
-        Some(token) => Some(token),
+/// Reads the next element of a value expression; or the error in a closure that was opened but couldn't be read.
+pub fn _try_to_read_value_expression_element(cursor: &mut Cursor) -> Result<Option<Token>, Error> {
+    if let Some(token) = invocation::prefixed::Parser::Try_Parse_At(cursor) {
+        return Ok(Some(token));
+    }
+
+    if let Some(token) = primitive::Parser::Try_Parse_At(cursor) {
+        return Ok(Some(token));
     }
+
+    if closure::is_opener(cursor.curr()) {
+        return match closure::Parser::Parse_Opt_At(cursor) {
+            Parsed::Pass(token) => Ok(Some(token)),
+            Parsed::Fail(Some(error)) => Err(error),
+            Parsed::Fail(None) => Ok(None),
+        };
+    }
+
+    Ok(word::Parser::Try_Parse_At(cursor))
 }
@@ -25,9 +25,13 @@ macro_rules! token {
         $rule:expr
         $(,tests: $($tests:expr)*)?
         $(,subs: [$($subs:ident $(,)?)*])?
+        $(,variants: [$($variants:ident $(,)?)*])?
     ) => {
         pub const KEY: &str = stringify!($key);
 
+        /// A checked view of a token made by this parser.
+        pub type View<'t> = crate::parser::results::view::View<'t, Parser>;
+
         #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
         pub struct Parser;
         impl crate::parser::Parser for Parser {
@@ -59,6 +63,11 @@ macro_rules! token {
                 vec![$(stringify!($tags),)*]
             }
 
+            $(fn variants(&self) -> Vec<Box<dyn crate::parser::Parser>> {
+                crate::parser::tokens::imports!();
+                vec![$(Box::new($variants::Parser::Get()),)*]
+            })?
+
             fn rule(
                 &self,
                 cursor: &mut crate::parser::cursor::Cursor,
@@ -129,7 +138,8 @@ macro_rules! splay {
                     ]
                 )
             },
-            subs: [$($subs,)*]
+            subs: [$($subs,)*],
+            variants: [$($parsers,)*]
         }
     };
 }
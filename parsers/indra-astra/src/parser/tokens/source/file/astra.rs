@@ -1,19 +1,21 @@
 use crate::parser::{
-    results::span::Span,
+    cursor::Cursor,
+    results::{span::Span, token::Token},
     tokens::{
         attribute::group,
         expression::{
             assignment::{self, entry, func, var},
-            invocation,
             literal::{
                 markup,
                 primitive::{self, number},
-                structure::tree,
+                structure::{closure, tree},
             },
         },
         source::file::{self, data},
         token,
+        whitespace::indent,
     },
+    Parser as _,
 };
 
 token! {
@@ -43,7 +45,7 @@ token! {
             cursor.curr_indent(),
         );
     } else {
-        if let Some(prim_data) = primitive::Parser::Try_Parse_At(cursor) {
+        if let Some(prim_data) = _try_parse_lone_primitive(cursor) {
             if prim_data.tag(number::KEY) {
                 return End::ToDo("Check for data math expression");
             } else {
@@ -51,9 +53,15 @@ token! {
             }
         } else if let Some(struct_data) = data::Parser::Try_Parse_At(cursor) {
             return End::ToDo("Check for data strut literal expression");
-        } else if let Some(prefixed_expression) = invocation::prefixed::Parser::Try_Parse_At(cursor) {
-            return End::ToDo("Check for prefixed expression (assume strux)");
-        } else if let Some(tree) = tree::Parser::Try_Parse_At(cursor) {
+        } else if let Some(tree) = match tree::Parser::Parse_Opt_At(cursor) {
+            // prefixed expressions are assumed to be strux; and are read as the tree's first branch.
+            Parsed::Pass(tree) => Some(tree),
+            // mixed indentation and unclosed closures can't be read as markup instead either.
+            Parsed::Fail(Some(error)) if indent::Find_Mixed(&error).is_some() || closure::Find_Unclosed(&error).is_some() => {
+                return End::Error_In_Child_Of(Token::Of_Type::<data::Parser>(), Some(error));
+            }
+            Parsed::Fail(_) => None,
+        } {
             let mut file = Token::Of_Type::<data::Parser>();
             match preceeding_attributes {
                 Some(attributes) => {
@@ -135,3 +143,18 @@ token! {
     }
   }
 }
+
+/// Parses a primitive; only if it's the whole rest of the file, like `42`, and not the start of something else, like `1: one`.
+fn _try_parse_lone_primitive(cursor: &mut Cursor) -> Option<Token> {
+    cursor.save();
+    if let Some(primitive) = primitive::Parser::Try_Parse_At(cursor) {
+        cursor.skip_ws();
+        if cursor.is_eof() {
+            cursor.pop();
+            return Some(primitive);
+        }
+    }
+
+    cursor.restore();
+    None
+}
@@ -1,4 +1,4 @@
-use crate::parser::tokens::token;
+use crate::parser::tokens::{expression::literal::structure::tree, token};
 
 token! {
     data_file => |cursor: &mut Cursor| {
@@ -11,6 +11,11 @@ token! {
                     End::ToDo("read as value file")
                 },
                 fs::Data::StruX(struct_type) => match struct_type {
+                    // ...arr
+                    fs::Struct::Array => match tree::Parser::Parse_Opt_At(cursor) {
+                        Parsed::Pass(tree) => Token::New().prop("value", tree).to_end(),
+                        Parsed::Fail(error) => End::Error_In_Prop(&KEY, "value", error),
+                    },
                     _ => End::ToDo("read as strux file")
                 },
             },
@@ -1,6 +1,10 @@
+use crate::parser::results::{token::Token, view::ViewError};
 use crate::parser::tokens::{
     expression::assignment,
-    expression::{self, literal::structure::tree},
+    expression::{
+        self,
+        literal::structure::tree::{self, Tree},
+    },
     statement::branch,
     token,
     whitespace::indent,
@@ -45,5 +49,55 @@ token! {
                 }
             }
         }
+    },
+    tests:
+        unit!(["Nested Ordered Entry"]
+            : "- a\n\t- b"
+            => Parsed::Pass(Token::New()
+                .name(&KEY)
+                .child(Token::New()
+                    .name(tree::KEY)
+                    .partial()
+                    .child(Token::New()
+                        .name(&KEY)
+                        .child(Token::New()
+                            .name(assignment::entry::ordered::KEY)
+                            .partial()
+                            .build_from(0, 2))
+                        .build_from(0, 2))
+                    .child(Token::New()
+                        .name(&KEY)
+                        .child(Token::New()
+                            .name(assignment::entry::ordered::KEY)
+                            .partial()
+                            .build_from(5, 7))
+                        .build_from(5, 7))
+                    .build_from(0, 7))
+                .build_from(0, 7)))
+}
+
+/// A typed view of a branch in a tree.
+#[derive(Debug, Clone)]
+pub struct Branch<'t> {
+    pub token: &'t Token,
+    /// The branch's entry or expression; unless it's been nested within the branch's tree.
+    pub value: Option<&'t Token>,
+    pub tree: Option<Tree<'t>>,
+}
+
+impl<'t> TryFrom<&'t Token> for Branch<'t> {
+    type Error = ViewError;
+
+    fn try_from(token: &'t Token) -> Result<Self, Self::Error> {
+        let view = View::try_from(token)?;
+        let tree = view.children().iter().find(|child| child.name == tree::KEY);
+        Ok(Branch {
+            token,
+            value: view.children().iter().find(|child| child.name != tree::KEY),
+            tree: match tree {
+                Some(tree) => Some(Tree::try_from(tree)?),
+                None => None,
+            },
+        })
     }
 }
@@ -5,7 +5,9 @@ pub const CONST_TAG: &str = "const";
 
 token! {
   var_assigner => |cursor: &mut Cursor| {
-    if cursor.try_read('=') {
+    // `=>` is a func assigner
+    if cursor.curr_is('=') && !cursor.next_is('>') {
+      cursor.read();
       return End::New().tag(CONST_TAG).to_end();
     } else if cursor.try_read('~') && cursor.try_read('=') {
       return End::New().tag(MUT_TAG).to_end();
@@ -1,6 +1,7 @@
 use crate::parser::{
     cursor::Cursor,
-    results::{builder::Builder, error::Error, parsed::Parsed, token::Token},
+    indents::{IndentStyle, Indentation},
+    results::{builder::Builder, end::End, error::Error, parsed::Parsed, token::Token},
     tokens::token,
     Parser as _,
 };
@@ -9,6 +10,8 @@ pub mod current;
 pub mod decrease;
 pub mod increase;
 
+pub const MIXED_KEY: &str = "mixed_indentation";
+
 pub enum Indents {
     Increase(Token),
     Decrease(Token),
@@ -20,7 +23,7 @@ pub enum Indents {
 token! {
     indent => |cursor: &mut Cursor| {
         let start = cursor.curr_pos();
-        if cursor.is_eof() {
+        if cursor.is_eof() || cursor.is_delimited() {
             return End::None;
         }
 
@@ -29,6 +32,10 @@ token! {
             return End::None;
         }
 
+        if let Some(mixed) = cursor.indent().mixed {
+            return Mixed(cursor, mixed);
+        }
+
         if cursor.indent().curr > cursor.indent().prev() {
             End::New_Variant::<increase::Parser>(&KEY)
         } else if cursor.indent().curr < cursor.indent().prev() {
@@ -36,7 +43,104 @@ token! {
         } else {
             End::New_Variant::<current::Parser>(&KEY)
         }
+    },
+    tests:
+        unit!(["Tabs" & "Increase"]
+            : "\n\t\tx"
+            => Parsed::Pass(Token::New()
+                .name(increase::KEY)
+                .partial()
+                .build_from(0, 2)))
+        unit!(["Tabs" & "Mixed"]
+            : "\n\t x"
+            => Parsed::Fail(Some(Error::New(MIXED_KEY)
+                .tag("indentation")
+                .partial()
+                .build_from(2, 2)
+                .unwrap())),
+            indentation: Indentation::New(IndentStyle::Detect, 4))
+        unit!(["Spaces" & "Mixed"]
+            : "\n  \tx"
+            => Parsed::Fail(Some(Error::New(MIXED_KEY)
+                .tag("indentation")
+                .partial()
+                .build_from(3, 3)
+                .unwrap())),
+            indentation: Indentation::New(IndentStyle::Detect, 4))
+        unit!(["Tabs" & "Mixed" & "Any"]
+            : "\n\t x"
+            => Parsed::Pass(Token::New()
+                .name(increase::KEY)
+                .partial()
+                .build_from(0, 2)))
+        unit!(["Spaces Only" & "Spaces"]
+            : "\n  x"
+            => Parsed::Pass(Token::New()
+                .name(increase::KEY)
+                .partial()
+                .build_from(0, 2)),
+            indentation: Indentation::New(IndentStyle::SpacesOnly, 4))
+        unit!(["Spaces Only" & "Tab"]
+            : "\n\tx"
+            => Parsed::Fail(Some(Error::New(MIXED_KEY)
+                .tag("indentation")
+                .partial()
+                .build_from(1, 1)
+                .unwrap())),
+            indentation: Indentation::New(IndentStyle::SpacesOnly, 4))
+        unit!(["Tabs Only" & "Tabs"]
+            : "\n\tx"
+            => Parsed::Pass(Token::New()
+                .name(increase::KEY)
+                .partial()
+                .build_from(0, 1)),
+            indentation: Indentation::New(IndentStyle::TabsOnly, 4))
+        unit!(["Tabs Only" & "Spaces"]
+            : "\n  x"
+            => Parsed::Fail(Some(Error::New(MIXED_KEY)
+                .tag("indentation")
+                .partial()
+                .build_from(1, 2)
+                .unwrap())),
+            indentation: Indentation::New(IndentStyle::TabsOnly, 4))
+}
+
+/// The error for a line whose indentation breaks the context's indentation policy; starting at the first offending char.
+#[allow(non_snake_case)]
+fn Mixed(cursor: &Cursor, mixed: usize) -> End {
+    let expected = match cursor.context().indentation.style {
+        IndentStyle::TabsOnly => "tabs",
+        IndentStyle::SpacesOnly => "spaces",
+        _ => match cursor.indent().detected {
+            Some('\t') => "tabs",
+            _ => "spaces",
+        },
+    };
+
+    Error::New(MIXED_KEY)
+        .text(&format!(
+            "Expected indentation using only {}, but found {}.",
+            expected,
+            if cursor.at(mixed) == '\t' { "a tab" } else { "a space" }
+        ))
+        .tag("indentation")
+        .tag("invalid")
+        .start(mixed)
+        .end(cursor.prev_pos())
+        .to_end()
+}
+
+/// Finds a mixed indentation error within the given error; if it was caused by one.
+#[allow(non_snake_case)]
+pub fn Find_Mixed(error: &Error) -> Option<&Error> {
+    if error.name == MIXED_KEY {
+        return Some(error);
     }
+
+    error.children.iter().find_map(|child| match child {
+        Parsed::Fail(Some(child)) => Find_Mixed(child),
+        _ => None,
+    })
 }
 
 #[allow(non_snake_case)]
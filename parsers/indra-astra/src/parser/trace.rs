@@ -0,0 +1,228 @@
+use serde::{Deserialize, Serialize};
+
+use super::results::parsed::Parsed;
+
+/// How a parser attempt ended.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Match,
+    Fail,
+    None,
+}
+
+impl Outcome {
+    #[allow(non_snake_case)]
+    pub fn Of(result: &Parsed) -> Outcome {
+        match result {
+            Parsed::Pass(_) => Outcome::Match,
+            Parsed::Fail(Some(_)) => Outcome::Fail,
+            Parsed::Fail(None) => Outcome::None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Outcome::Match => "match",
+            Outcome::Fail => "fail",
+            Outcome::None => "none",
+        }
+    }
+}
+
+/// A single step taken while parsing.
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    /// A parser started at the given position.
+    Enter { parser: String, pos: usize },
+    /// A parser finished; with the span of its token or error, and the cursor position it left behind.
+    Exit {
+        parser: String,
+        outcome: Outcome,
+        start: usize,
+        end: usize,
+        pos: usize,
+        /// If the result was recalled from the memo table instead of being parsed.
+        memoized: bool,
+    },
+    /// The cursor state was saved, to possibly backtrack to later.
+    Save { pos: usize },
+    /// The cursor backtracked to a saved state.
+    Restore { from: usize, to: usize },
+    /// A saved cursor state was dropped; keeping the current position.
+    Pop { pos: usize },
+}
+
+impl Event {
+    /// The exit event for a parser's result.
+    #[allow(non_snake_case)]
+    pub fn Exit_Of(parser: &str, result: &Parsed, pos: usize, memoized: bool) -> Event {
+        let outcome = Outcome::Of(result);
+        let (start, end) = match outcome {
+            Outcome::None => (pos, pos),
+            _ => (result.get_start(), result.get_end()),
+        };
+
+        Event::Exit {
+            parser: parser.to_string(),
+            outcome,
+            start,
+            end,
+            pos,
+            memoized,
+        }
+    }
+}
+
+/// An in-memory record of every parser attempt and cursor backtrack.
+#[derive(PartialEq, Eq, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Trace {
+    events: Vec<Event>,
+}
+
+impl Trace {
+    #[allow(non_snake_case)]
+    pub fn New() -> Trace {
+        Trace { events: Vec::new() }
+    }
+
+    pub fn push(&mut self, event: Event) {
+        self.events.push(event);
+    }
+
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// The number of times the cursor moved back to an earlier position.
+    pub fn backtracks(&self) -> usize {
+        self.events
+            .iter()
+            .filter(|event| matches!(event, Event::Restore { from, to } if to < from))
+            .count()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.events).unwrap()
+    }
+
+    /// Renders the trace as a tree; with each parser's steps indented beneath it.
+    pub fn to_tree_str(&self) -> String {
+        let mut result = String::new();
+        let mut depth = 0;
+        for event in &self.events {
+            let line = match event {
+                Event::Enter { parser, pos } => {
+                    depth += 1;
+                    result.push_str(&format!("{}{} @ {}\n", "  ".repeat(depth - 1), parser, pos));
+                    continue;
+                }
+                Event::Exit {
+                    parser,
+                    outcome,
+                    start,
+                    end,
+                    pos,
+                    memoized,
+                } => {
+                    depth = depth.saturating_sub(1);
+                    let span = match outcome {
+                        Outcome::None => String::new(),
+                        _ => format!(" {}..{}", start, end),
+                    };
+
+                    result.push_str(&format!(
+                        "{}{} => {}{} @ {}{}\n",
+                        "  ".repeat(depth),
+                        parser,
+                        outcome.as_str(),
+                        span,
+                        pos,
+                        if *memoized { " (memo)" } else { "" }
+                    ));
+                    continue;
+                }
+                Event::Save { pos } => format!("save @ {}", pos),
+                Event::Restore { from, to } => {
+                    if to < from {
+                        format!("backtrack {} ~> {}", from, to)
+                    } else {
+                        format!("restore {} ~> {}", from, to)
+                    }
+                }
+                Event::Pop { pos } => format!("pop @ {}", pos),
+            };
+
+            result.push_str(&format!("{}{}\n", "  ".repeat(depth), line));
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _sample() -> Trace {
+        let mut trace = Trace::New();
+        for event in [
+            Event::Enter {
+                parser: "a".to_string(),
+                pos: 0,
+            },
+            Event::Save { pos: 0 },
+            Event::Enter {
+                parser: "b".to_string(),
+                pos: 0,
+            },
+            Event::Exit {
+                parser: "b".to_string(),
+                outcome: Outcome::None,
+                start: 2,
+                end: 2,
+                pos: 2,
+                memoized: false,
+            },
+            Event::Restore { from: 2, to: 0 },
+            Event::Restore { from: 0, to: 0 },
+            Event::Exit {
+                parser: "a".to_string(),
+                outcome: Outcome::Match,
+                start: 0,
+                end: 1,
+                pos: 2,
+                memoized: true,
+            },
+        ] {
+            trace.push(event);
+        }
+
+        trace
+    }
+
+    #[test]
+    fn renders_as_a_tree() {
+        assert_eq!(
+            _sample().to_tree_str(),
+            "a @ 0\n  save @ 0\n  b @ 0\n  b => none @ 2\n  backtrack 2 ~> 0\n  restore 0 ~> 0\na => match 0..1 @ 2 (memo)\n"
+        );
+    }
+
+    #[test]
+    fn counts_only_backwards_restores_as_backtracks() {
+        assert_eq!(_sample().backtracks(), 1);
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let trace = _sample();
+        let events: Vec<Event> = serde_json::from_str(&trace.to_json()).unwrap();
+        assert_eq!(events, trace.events());
+    }
+}
@@ -5,9 +5,23 @@ use std::collections::{HashMap, HashSet};
 use crate::{
     parser::{
         self,
+        context::Context,
+        cst::Cst,
+        cursor::Cursor,
+        grammar::Grammar,
+        incremental::{Document, Edit},
+        indents::Indentation,
+        limits::Limits,
         results::{
-            builder::Builder, error::Error, error_builder::ErrorBuilder, node::Node,
-            parsed::Parsed, span::Span, token::Token, token_builder::TokenBuilder,
+            builder::Builder,
+            error::Error,
+            error_builder::ErrorBuilder,
+            node::Node,
+            parsed::Parsed,
+            span::Span,
+            token::Token,
+            token_builder::TokenBuilder,
+            visit::{Fold, Path},
         },
     },
     utils::{
@@ -16,6 +30,9 @@ use crate::{
     },
 };
 
+#[cfg(feature = "log")]
+use crate::parser::results::diff::Diff;
+
 pub struct Test {
     parser: Box<dyn parser::Parser>,
     tags: Vec<String>,
@@ -24,6 +41,12 @@ pub struct Test {
     is_partial: bool,
     sub_types: Vec<String>,
     is_disabled: bool,
+    /// How the input's indentation is measured and validated.
+    indentation: Indentation,
+    /// How much work parsing the input may do.
+    limits: Limits,
+    /// Whether a passing result's concrete syntax tree must also reproduce the input.
+    lossless: bool,
 }
 
 impl Clone for Test {
@@ -36,6 +59,9 @@ impl Clone for Test {
             is_partial: self.is_partial,
             sub_types: self.sub_types.clone(),
             is_disabled: self.is_disabled,
+            indentation: self.indentation,
+            limits: self.limits,
+            lossless: self.lossless,
         }
     }
 }
@@ -58,6 +84,9 @@ impl Test {
             is_partial: false,
             sub_types: Vec::new(),
             is_disabled: false,
+            indentation: Indentation::Default(),
+            limits: Limits::Default(),
+            lossless: false,
         }
     }
 
@@ -74,6 +103,9 @@ impl Test {
             is_partial: false,
             sub_types: Vec::new(),
             is_disabled: false,
+            indentation: Indentation::Default(),
+            limits: Limits::Default(),
+            lossless: false,
         }
     }
 
@@ -90,6 +122,9 @@ impl Test {
             is_partial: true,
             sub_types: Vec::new(),
             is_disabled: false,
+            indentation: Indentation::Default(),
+            limits: Limits::Default(),
+            lossless: false,
         }
     }
 
@@ -111,6 +146,9 @@ impl Test {
             is_partial: false,
             sub_types,
             is_disabled: false,
+            indentation: Indentation::Default(),
+            limits: Limits::Default(),
+            lossless: false,
         }
     }
 
@@ -131,6 +169,9 @@ impl Test {
             is_partial: false,
             sub_types,
             is_disabled: false,
+            indentation: Indentation::Default(),
+            limits: Limits::Default(),
+            lossless: false,
         }
     }
 
@@ -151,6 +192,9 @@ impl Test {
             is_partial: true,
             sub_types,
             is_disabled: false,
+            indentation: Indentation::Default(),
+            limits: Limits::Default(),
+            lossless: false,
         }
     }
 
@@ -169,6 +213,36 @@ impl Test {
             }
     }
 
+    /// Parses the input with the given indentation policy; instead of the default one.
+    pub fn with_indentation(mut self, indentation: Indentation) -> Test {
+        self.indentation = indentation;
+        self
+    }
+
+    /// Parses the input with the given limits; instead of none.
+    pub fn with_limits(mut self, limits: Limits) -> Test {
+        self.limits = limits;
+        self
+    }
+
+    /// Checks that a passing result's concrete syntax tree reproduces the input; as well as the expected result.
+    pub fn with_lossless(mut self, lossless: bool) -> Test {
+        self.lossless = lossless;
+        self
+    }
+
+    /// The context the input is parsed in.
+    pub fn context(&self) -> Context {
+        Context::new_empty().with_indentation(self.indentation)
+    }
+
+    /// A cursor over the given input; in the test's context and with its limits.
+    fn cursor<'s>(&self, input: &'s str) -> Cursor<'s> {
+        let mut cursor = Cursor::New_With(input, self.context());
+        cursor.set_limits(self.limits);
+        cursor
+    }
+
     pub fn get_name(&self) -> String {
         self.tags.join(" & ")
     }
@@ -182,19 +256,20 @@ impl Test {
     }
 
     pub fn run(self) -> Vec<Outcome> {
-        let parsers = parser::get_all();
+        let grammar = Grammar::Default();
         self.run_with_context(
-            parsers,
+            &grammar,
             &Settings {
                 panic_on_fail: false,
                 test_tags: vec![],
                 test_types: vec![],
+                random_edits: DEFAULT_RANDOM_EDITS,
             },
         )
     }
 
     pub fn run_with_context(self, parsers: ParserMap, settings: &Settings) -> Vec<Outcome> {
-        let outcomes: Vec<Outcome>;
+        let mut outcomes: Vec<Outcome>;
 
         #[cfg(feature = "log")]
         let test_name = &self.parser.name();
@@ -207,8 +282,10 @@ impl Test {
 
         if !self.is_disabled() {
             if self.sub_types.len() == 0 {
+                let edits = _run_random_edit_tests(&self, settings);
                 outcomes = vec![_run_unit_test(self)];
                 _check_for_panic_on_fail(&outcomes.first().unwrap(), settings);
+                outcomes.extend(edits);
             } else {
                 outcomes = _run_tests_for_pattern(self, parsers, settings);
             }
@@ -385,6 +462,9 @@ impl Test {
                         is_partial: self.is_partial,
                         sub_types: self.sub_types.clone(),
                         is_disabled: self.is_disabled,
+                        indentation: self.indentation,
+                        limits: self.limits,
+                        lossless: self.lossless,
                     };
 
                     tests.push(case);
@@ -496,6 +576,15 @@ macro_rules! unit {
     ([$($tag:literal $(&)?)*]: $input:literal => $expected:expr) => {
         Test::Unit::<Self>(&[$($tag,)*], $input, $expected)
     };
+    ([$($tag:literal $(&)?)*]: $input:literal => $expected:expr, indentation: $indentation:expr) => {
+        Test::Unit::<Self>(&[$($tag,)*], $input, $expected).with_indentation($indentation)
+    };
+    ([$($tag:literal $(&)?)*]: $input:literal => $expected:expr, limits: $limits:expr) => {
+        Test::Unit::<Self>(&[$($tag,)*], $input, $expected).with_limits($limits)
+    };
+    ([$($tag:literal $(&)?)*]: $input:literal => $expected:expr, lossless: $lossless:expr) => {
+        Test::Unit::<Self>(&[$($tag,)*], $input, $expected).with_lossless($lossless)
+    };
 }
 pub(crate) use unit;
 
@@ -604,32 +693,37 @@ enum Comparison {
     Fail(String),
 }
 
-pub type ParserMap<'p> = &'p HashMap<String, Box<dyn parser::Parser>>;
+pub type ParserMap<'p> = &'p Grammar;
 
 #[derive(Debug)]
 pub struct Settings {
     pub panic_on_fail: bool,
     pub test_types: Vec<String>,
     pub test_tags: Vec<String>,
+    /// The number of random edits to re-parse each test input with; checking each against a fresh parse.
+    pub random_edits: usize,
 }
 
+/// The number of random edits each test input is re-parsed with by default.
+pub const DEFAULT_RANDOM_EDITS: usize = 3;
+
 pub fn run_all() -> Vec<Outcome> {
-    run_all_with_settings(&Settings {
+    run_all_with_settings(&Grammar::Default(), &Settings {
         panic_on_fail: false,
         test_types: vec![],
         test_tags: vec![],
+        random_edits: DEFAULT_RANDOM_EDITS,
     })
 }
 
-pub fn run_all_with_settings(settings: &Settings) -> Vec<Outcome> {
+pub fn run_all_with_settings(grammar: &Grammar, settings: &Settings) -> Vec<Outcome> {
     log::color!("TESTS", Color::Yellow);
     log::color!("TOKEN", Color::Blue);
     log::bg!(":START", Color::BrightGreen);
     log::bg!(":END", Color::BrightMagenta);
     log::push_unique!("TESTS");
     log::push!("*");
-    let parsers = parser::get_all();
-    let result = run_all_for(parsers, settings);
+    let result = run_all_for(grammar, settings);
     log::pop_unique!("TESTS");
     log::pop!();
     return result;
@@ -637,7 +731,7 @@ pub fn run_all_with_settings(settings: &Settings) -> Vec<Outcome> {
 
 pub fn run_all_for(parsers: ParserMap, settings: &Settings) -> Vec<Outcome> {
     let mut outcomes: Vec<Outcome> = Vec::new();
-    for parser in parsers.values() {
+    for parser in parsers.parsers() {
         if settings.test_types.len() > 0
             && !settings.test_types.contains(&parser.name().to_string())
         {
@@ -687,11 +781,12 @@ pub fn run_all_for(parsers: ParserMap, settings: &Settings) -> Vec<Outcome> {
 pub fn run(test: Test) -> Vec<Outcome> {
     run_in_context(
         test,
-        &parser::get_all(),
+        &Grammar::Default(),
         &Settings {
             panic_on_fail: false,
             test_types: vec![],
             test_tags: vec![],
+            random_edits: DEFAULT_RANDOM_EDITS,
         },
     )
 }
@@ -709,8 +804,11 @@ fn _run_unit_test(test: Test) -> Outcome {
     let input = &test.input;
     let expected = &test.expected;
 
-    let result = parser.parse(&input);
-    let comparison = _validate_outcome(&expected, &result);
+    let result = parser.parse_at(&mut test.cursor(input));
+    let comparison = match _validate_outcome(&expected, &result) {
+        Comparison::Pass if test.lossless => _validate_lossless(input, &result),
+        comparison => comparison,
+    };
     let result = match comparison {
         Comparison::Pass => Outcome::Pass(test),
         Comparison::Fail(message) => Outcome::Fail(test, result, message),
@@ -739,7 +837,7 @@ fn _run_unit_test(test: Test) -> Outcome {
             log::error!(
                 &[":END"],
                 &format!(
-                    "{}\n{}{} \t=> {}\n\n{}\n{}\n{}",
+                    "{}\n{}{} \t=> {}\n\n{}\n{}",
                     &_format_input(&_test.input, Some(InputDecoration::XMark)),
                     "✘".color(Color::Red),
                     " FAIL".color(Color::Red),
@@ -754,29 +852,10 @@ fn _run_unit_test(test: Test) -> Outcome {
                         .indent(2)
                         .color(Color::Yellow),
                     &format!(
-                        "\t- Expected:\n{}",
-                        match _test.expected {
-                            Parsed::Pass(ref token) => format!("{:#?}", token),
-                            Parsed::Fail(ref error) => match error {
-                                Some(error) => format!("{:#?}", error),
-                                None => "None".to_string(),
-                            },
-                        }
-                    )
-                    .indent(2)
-                    .color(Color::Green),
-                    &format!(
-                        "\t- Actual:\n{}",
-                        match _result {
-                            Parsed::Pass(ref token) => format!("{:#?}", token),
-                            Parsed::Fail(ref error) => match error {
-                                Some(error) => format!("{:#?}", error),
-                                None => "None".to_string(),
-                            },
-                        }
+                        "\t- Diff (- expected, + actual):\n{}",
+                        Diff::Of(&_without_mock_tags(&_test.expected), _result).to_unified_str()
                     )
-                    .indent(2)
-                    .color(Color::Red),
+                    .indent(2),
                 )
             );
         }
@@ -802,11 +881,13 @@ fn _run_tests_for_pattern(base: Test, parsers: ParserMap, settings: &Settings) -
     );
 
     for combo in combos {
+        let edits = _run_random_edit_tests(&combo, settings);
         let outcome = _run_unit_test(combo);
 
         _check_for_panic_on_fail(&outcome, settings);
 
         outcomes.push(outcome);
+        outcomes.extend(edits);
     }
 
     log::pop!();
@@ -814,6 +895,107 @@ fn _run_tests_for_pattern(base: Test, parsers: ParserMap, settings: &Settings) -
     return outcomes;
 }
 
+/// Applies random edits to the test's input one at a time; checking each incremental re-parse against a fresh parse.
+fn _run_random_edit_tests(test: &Test, settings: &Settings) -> Vec<Outcome> {
+    const INSERTS: [&str; 10] = [" ", "\n", "\t", ":", ": ", "a", "bc", "#", ".", "1"];
+    let mut outcomes = Vec::new();
+    // documents don't apply limits; so their re-parses couldn't match the fresh ones.
+    if settings.random_edits == 0 || test.limits != Limits::Default() {
+        return outcomes;
+    }
+
+    let mut seed = {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        std::hash::Hash::hash(&test.input, &mut hasher);
+        std::hash::Hasher::finish(&hasher) | 1
+    };
+    let mut random = |max: usize| {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        (seed % (max as u64 + 1)) as usize
+    };
+
+    let mut document = Document::New_With(&test.input, test.parser.get(), test.context());
+    for _ in 0..settings.random_edits {
+        let len = document.source().chars().count();
+        let start = random(len);
+        let end = (start + random(3)).min(len);
+        let edit = Edit::New(start, end, INSERTS[random(INSERTS.len() - 1)]);
+
+        let edited = edit.apply_to(document.source());
+        let mut edited_test = test.clone();
+        edited_test.input = edited.clone();
+        edited_test.tags.push("incremental".to_string());
+
+        let parser = test.parser.get();
+        let parsed = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let fresh = parser.parse_at(&mut test.cursor(&edited));
+            (document.edit(&[edit.clone()]).clone(), fresh)
+        }));
+
+        let outcome = match parsed {
+            // the document can't be edited any further once its parser has panicked.
+            Err(panic) => {
+                let message = format!(
+                    "Parsing the input after {:?} panicked: {}",
+                    edit,
+                    _panic_message(&panic)
+                );
+                log::error!(&["INCREMENTAL", ":END"], &message);
+                let outcome = Outcome::Fail(edited_test, Parsed::Fail(None), message);
+                _check_for_panic_on_fail(&outcome, settings);
+                outcomes.push(outcome);
+                break;
+            }
+            Ok((result, fresh)) if result == fresh => Outcome::Pass(edited_test),
+            Ok((result, _)) => {
+                let message = format!(
+                    "Incremental re-parse after {:?} differs from a fresh parse of the edited input.",
+                    edit
+                );
+                log::error!(&["INCREMENTAL", ":END"], &message);
+                Outcome::Fail(edited_test, result, message)
+            }
+        };
+
+        _check_for_panic_on_fail(&outcome, settings);
+        outcomes.push(outcome);
+    }
+
+    outcomes
+}
+
+/// The message a parser panicked with; if it was text.
+fn _panic_message(panic: &Box<dyn std::any::Any + Send>) -> String {
+    match panic.downcast_ref::<&str>() {
+        Some(message) => message.to_string(),
+        None => match panic.downcast_ref::<String>() {
+            Some(message) => message.clone(),
+            None => "<unknown>".to_string(),
+        },
+    }
+}
+
+/// The expected result without the tags used to mark mocks and partial tokens in tests.
+fn _without_mock_tags(expected: &Parsed) -> Parsed {
+    struct Unmock;
+    impl Fold for Unmock {
+        fn fold(&mut self, mut token: Token, _path: &Path) -> Option<Token> {
+            if let Some(tags) = token.tags.as_mut() {
+                tags.retain(|tag| !(tag.starts_with("_!__") && tag.ends_with("__!_")));
+            }
+
+            Some(token)
+        }
+    }
+
+    match expected {
+        Parsed::Pass(token) => Parsed::Pass(token.clone().fold(&mut Unmock).unwrap()),
+        failure => failure.clone(),
+    }
+}
+
 fn _check_for_panic_on_fail(outcome: &Outcome, settings: &Settings) {
     if let Outcome::Fail(test, _, _) = outcome {
         if settings.panic_on_fail {
@@ -822,6 +1004,24 @@ fn _check_for_panic_on_fail(outcome: &Outcome, settings: &Settings) {
     }
 }
 
+/// Checks that the concrete syntax tree of a passing result reproduces its input.
+fn _validate_lossless(input: &str, result: &Parsed) -> Comparison {
+    match result {
+        Parsed::Pass(token) => {
+            let text = Cst::Of(token, input).to_string();
+            if text == input {
+                Comparison::Pass
+            } else {
+                Comparison::Fail(format!(
+                    "Expected the concrete syntax tree to reproduce the input; but it produced: {:?}.",
+                    text
+                ))
+            }
+        }
+        Parsed::Fail(_) => Comparison::Pass,
+    }
+}
+
 fn _validate_outcome(expected: &Parsed, result: &Parsed) -> Comparison {
     match result {
         Parsed::Pass(resulting_pass) => match expected {